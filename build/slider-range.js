@@ -0,0 +1 @@
+import{a6 as n,_ as d,t as o,i as u,e as l,n as h,R as p,x as v,o as b,l as f,D as g,J as y,d as S}from"./core.js";import{SliderBase as Q,styles as E}from"./slider.js";export{a6 as Thumb}from"./core.js";class et extends Q{constructor(){super(...arguments),this.valueStart=0,this.startThumbWithIndicator=!1,this.startThumbTop=!1,this.shouldRenderStartRipple=!1,this.startThumbTransformStyle="",this.startThumbTransitionStyle="",this.startThumbCssProperties={},this.startRippleHandlers=new p((()=>(this.shouldRenderStartRipple=!0,this.startRipple)))}update(t){if((t.has("valueStart")||t.has("valueEnd"))&&this.mdcFoundation){const t=this.mdcFoundation,e=()=>{t.setValueStart(Math.min(this.valueStart,t.getValue())),this.valueStart=t.getValueStart()},i=()=>{t.setValue(Math.max(this.valueEnd,t.getValueStart())),this.valueEnd=t.getValue()};this.valueStart>t.getValue()?(i(),e()):(e(),i())}super.update(t)}renderRootEl(t){const e=b({"mdc-slider--range":!0,"mdc-slider--disabled":this.disabled,"mdc-slider--discrete":this.discrete});return v`<div class="mdc-slider ${e}" @pointerdown=${this.onPointerdown} @pointerup=${this.onPointerup} @contextmenu=${this.onContextmenu}>${t}</div>`}renderStartInput(){var t;return v`<input class="mdc-slider__input start" type="range" step=${this.step} min=${this.min} max=${this.valueEnd} .value=${this.valueStart} @change=${this.onStartChange} @focus=${this.onStartFocus} @blur=${this.onStartBlur} ?disabled=${this.disabled} aria-label=${f(this.ariaLabel)} aria-labelledby=${f(this.ariaLabelledBy)} aria-describedby=${f(this.ariaDescribedBy)} aria-valuetext=${f(null===(t=this.valueToAriaTextTransform)||void 0===t?void 0:t.call(this,this.valueStart))}>`}renderEndInput(){var t;return v`<input class="mdc-slider__input end" type="range" step=${this.step} min=${this.valueStart} max=${this.max} .value=${this.valueEnd} @change=${this.onEndChange} @focus=${this.onEndFocus} @blur=${this.onEndBlur} ?disabled=${this.disabled} aria-label=${f(this.ariaLabel)} aria-labelledby=${f(this.ariaLabelledBy)} aria-describedby=${f(this.ariaDescribedBy)} aria-valuetext=${f(null===(t=this.valueToAriaTextTransform)||void 0===t?void 0:t.call(this,this.valueEnd))}>`}renderTrack(){const t=(this.valueStart-this.min)/(this.max-this.min)*100,e=g({"transform-origin":this.trackTransformOriginStyle,left:this.trackLeftStyle||"rtl"===getComputedStyle(this).direction?this.trackLeftStyle:`${t}%`,right:this.trackRightStyle||"rtl"!==getComputedStyle(this).direction?this.trackRightStyle:`${t}%`,"-webkit-transform":`scaleX(${(this.valueEnd-this.valueStart)/(this.max-this.min)})`,transform:`scaleX(${(this.valueEnd-this.valueStart)/(this.max-this.min)})`,"-webkit-transition":this.trackTransitionStyle,transition:this.trackTransitionStyle});return v`<div class="mdc-slider__track"><div class="mdc-slider__track--inactive"></div><div class="mdc-slider__track--active"><div class="mdc-slider__track--active_fill" style=${e}></div></div></div>`}renderStartThumb(){const t=b({"mdc-slider__thumb--with-indicator":this.startThumbWithIndicator,"mdc-slider__thumb--top":this.startThumbTop}),e=(this.valueStart-this.min)/(this.max-this.min)*100,i=g(Object.assign({"-webkit-transform":this.startThumbTransformStyle,transform:this.startThumbTransformStyle,"-webkit-transition":this.startThumbTransitionStyle,transition:this.startThumbTransitionStyle,left:this.startThumbTransformStyle||"rtl"===getComputedStyle(this).direction?"":`calc(${e}% - 24px)`,right:this.startThumbTransformStyle||"rtl"!==getComputedStyle(this).direction?"":`calc(${e}% - 24px)`},this.startThumbCssProperties)),r=this.shouldRenderStartRipple?v`<mwc-ripple class="ripple" unbounded></mwc-ripple>`:"";return v`<div class="mdc-slider__thumb start ${t}" style=${i} @mouseenter=${this.onStartMouseenter} @mouseleave=${this.onStartMouseleave}>${r}${this.renderValueIndicator(this.valueToValueIndicatorTransform(this.valueStart))}<div class="mdc-slider__thumb-knob"></div></div>`}onStartChange(t){var e;this.valueStart=Number(t.target.value),null===(e=this.mdcFoundation)||void 0===e||e.handleInputChange(n.START)}onStartFocus(){var t;null===(t=this.mdcFoundation)||void 0===t||t.handleInputFocus(n.START),this.startRippleHandlers.startFocus()}onStartBlur(){var t;null===(t=this.mdcFoundation)||void 0===t||t.handleInputBlur(n.START),this.startRippleHandlers.endFocus()}onStartMouseenter(){var t;null===(t=this.mdcFoundation)||void 0===t||t.handleThumbMouseenter(),this.startRippleHandlers.startHover()}onStartMouseleave(){var t;null===(t=this.mdcFoundation)||void 0===t||t.handleThumbMouseleave(),this.startRippleHandlers.endHover()}setFormData(t){this.name&&(t.append(`${this.name}-start`,`${this.valueStart}`),t.append(`${this.name}-end`,`${this.valueEnd}`))}createAdapter(){const t=t=>t===n.START,e=(t,e)=>{"mdc-slider__thumb--with-indicator"===t?this[(e===n.START?"start":"end")+"ThumbWithIndicator"]=!0:"mdc-slider__thumb--top"===t&&(this[(e===n.START?"start":"end")+"ThumbTop"]=!0)},i=(t,e)=>{"mdc-slider__thumb--with-indicator"===t?this[(e===n.START?"start":"end")+"ThumbWithIndicator"]=!1:"mdc-slider__thumb--top"===t&&(this[(e===n.START?"start":"end")+"ThumbTop"]=!1)},r=t=>t===n.START?this.startInput:this.formElement,a=t=>t===n.START?"start":"end";return{addClass:t=>{if("mdc-slider--disabled"===t)this.disabled=!0},removeClass:t=>{if("mdc-slider--disabled"===t)this.disabled=!1},hasClass:t=>{switch(t){case"mdc-slider--disabled":return this.disabled;case"mdc-slider--discrete":return this.discrete;case"mdc-slider--range":return!0;default:return!1}},addThumbClass:e,removeThumbClass:i,registerEventHandler:()=>{},deregisterEventHandler:()=>{},registerBodyEventHandler:(t,e)=>{document.body.addEventListener(t,e)},deregisterBodyEventHandler:(t,e)=>{document.body.removeEventListener(t,e)},registerInputEventHandler:(t,e,i)=>{r(t).addEventListener(e,i)},deregisterInputEventHandler:(t,e,i)=>{r(t).removeEventListener(e,i)},registerThumbEventHandler:()=>{},deregisterThumbEventHandler:()=>{},registerWindowEventHandler:(t,e)=>{window.addEventListener(t,e)},deregisterWindowEventHandler:(t,e)=>{window.removeEventListener(t,e)},emitChangeEvent:(t,e)=>{const i=new CustomEvent("change",{bubbles:!0,composed:!0,detail:{value:t,thumb:e}});this.dispatchEvent(i)},emitDragEndEvent:(e,i)=>{(t(i)?this.startRippleHandlers:this.endRippleHandlers).endPress()},emitDragStartEvent:(e,i)=>{(t(i)?this.startRippleHandlers:this.endRippleHandlers).startPress()},emitInputEvent:(t,e)=>{const i=new CustomEvent("input",{bubbles:!0,composed:!0,detail:{value:t,thumb:e}});this.dispatchEvent(i)},focusInput:t=>{r(t).focus()},getAttribute:()=>"",getBoundingClientRect:()=>this.mdcRoot.getBoundingClientRect(),getInputAttribute:(e,i)=>{switch(e){case"min":return this.min.toString();case"max":return this.max.toString();case"value":return(t(i)?this.valueStart:this.valueEnd).toString();case"step":return this.step.toString();default:return null}},getInputValue:e=>(t(e)?this.valueStart:this.valueEnd).toString(),getThumbBoundingClientRect:t=>this[a(t)+"Thumb"].getBoundingClientRect(),getThumbKnobWidth:t=>this[a(t)+"ThumbKnob"].getBoundingClientRect().width,getValueIndicatorContainerWidth:t=>this[a(t)+"ValueIndicatorContainer"].getBoundingClientRect().width,getValueToAriaValueTextFn:()=>this.valueToAriaTextTransform,isInputFocused:t=>{const e=y();return e[e.length-1]===r(t)},isRTL:()=>"rtl"===getComputedStyle(this).direction,setInputAttribute:()=>{},removeInputAttribute:()=>{},setThumbStyleProperty:(t,e,i)=>{const r=a(i);switch(t){case"transform":case"-webkit-transform":this[r+"ThumbTransformStyle"]=e;break;case"transition":case"-webkit-transition":this[r+"ThumbTransitionStyle"]=e;break;default:t.startsWith("--")&&(this[r+"ThumbCssProperties"][t]=e)}},removeThumbStyleProperty:(t,e)=>{switch(t){case"left":case"right":break;case"transition":case"-webkit-transition":this[a(e)+"ThumbTransitionStyle"]=""}},setTrackActiveStyleProperty:(t,e)=>{switch(t){case"transform-origin":this.trackTransformOriginStyle=e;break;case"left":this.trackLeftStyle=e;break;case"right":this.trackRightStyle=e;break;case"transform":case"-webkit-transform":break;case"transition":case"-webkit-transition":this.trackTransitionStyle=e}},removeTrackActiveStyleProperty:t=>{switch(t){case"transition":case"-webkit-transition":this.trackTransitionStyle=""}},setInputValue:(e,i)=>{t(i)?this.valueStart=Number(e):this.valueEnd=Number(e)},setPointerCapture:t=>{this.mdcRoot.setPointerCapture(t)},setValueIndicatorText:()=>{},updateTickMarks:t=>{this.tickMarks=t}}}}d([u("input.start")],et.prototype,"startInput",void 0),d([u(".start.mdc-slider__thumb")],et.prototype,"startThumb",void 0),d([u(".start.mdc-slider__thumb .mdc-slider__thumb-knob")],et.prototype,"startThumbKnob",void 0),d([u(".start.mdc-slider__thumb .mdc-slider__value-indicator-container")],et.prototype,"startValueIndicatorContainer",void 0),d([l(".start .ripple")],et.prototype,"startRipple",void 0),d([h({type:Number,reflect:!0})],et.prototype,"valueStart",void 0),d([o()],et.prototype,"startThumbWithIndicator",void 0),d([o()],et.prototype,"startThumbTop",void 0),d([o()],et.prototype,"shouldRenderStartRipple",void 0),d([o()],et.prototype,"startThumbTransformStyle",void 0),d([o()],et.prototype,"startThumbTransitionStyle",void 0),d([o()],et.prototype,"startThumbCssProperties",void 0);let it=class extends et{};it.styles=[E],it=d([S("mwc-slider-range")],it);export{it as SliderRange};
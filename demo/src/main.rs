@@ -1,14 +1,35 @@
 use std::collections::HashSet;
 
 use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
 use material_dioxus::{
-    dialog::{ActionType, MatDialogAction},
-    list::ListIndex,
-    text_inputs::{TextAreaCharCounter, TextFieldType, ValidityState, ValidityTransform},
+    dialog::{ActionType, DialogLink, MatDialogAction},
+    drawer::{DrawerType, MatDrawerAppContent, MatDrawerSubtitle, MatDrawerTitle},
+    form::{use_form, FieldValue, MatForm},
+    icon_button_toggle::{MatOffIconButtonToggle, MatOnIconButtonToggle},
+    list::{ListIndex, ListLink},
+    menu::Corner,
+    palette,
+    select::SelectLink,
+    slider::RangeValue,
+    snackbar::SnackbarLink,
+    text_inputs::{
+        validators::Validators, AsyncValidator, Date, FormattedValue, Formatter,
+        TextAreaCharCounter, TextFieldLink, TextFieldType, Time, ValidityState, ValidityTransform,
+    },
+    theming::{
+        use_density, use_persisted_theme_mode, use_theme_mode, Colors, Density, Shape, ThemeMode,
+        Typography,
+    },
+    top_app_bar::{MatTopAppBarActionItems, MatTopAppBarNavigationIcon, MatTopAppBarTitle},
     MatButton, MatCheckListItem, MatCheckbox, MatCircularProgress, MatCircularProgressFourColor,
-    MatDialog, MatFab, MatFormfield, MatIcon, MatIconButton, MatList, MatListItem,
-    MatListSeparator, MatRadio, MatRadioListItem, MatSwitch, MatTextArea, MatTextField, MatTheme,
+    MatDateField, MatDialog, MatDrawer, MatFab, MatFormfield, MatIcon, MatIconButton,
+    MatIconButtonToggle, MatLinearProgress, MatList, MatListItem, MatListSeparator, MatMenu,
+    MatNumberField, MatRadio, MatRadioListItem, MatRangeSlider, MatSelect, MatSlider, MatSnackbar,
+    MatSwitch, MatTab, MatTabBar, MatTextArea, MatTextField, MatTheme, MatThemeScope, MatTimeField,
+    MatTopAppBar,
 };
+use serde::{Deserialize, Serialize};
 
 fn main() {
     dioxus_web::launch(app);
@@ -23,6 +44,15 @@ fn app(cx: Scope) -> Element {
     let basic_dialog_open = use_state(cx, || false);
     let scrollable_dialog_open = use_state(cx, || false);
     let textarea_value = use_state(cx, String::new);
+    let snackbar_link = cx.use_hook(SnackbarLink::default);
+    let snackbar_open = use_state(cx, || false);
+    let select_value = use_state(cx, || String::from("apple"));
+    let select_link = cx.use_hook(SelectLink::default);
+    let dialog_link = cx.use_hook(DialogLink::default);
+    let textfield_link = cx.use_hook(TextFieldLink::default);
+    let textfield_valid = use_state(cx, || None);
+    use_persisted_theme_mode(cx, "material-dioxus-demo-theme-mode");
+    use_density(cx);
 
     render! {
         style {
@@ -51,7 +81,13 @@ html {{
 }}
 "
         }
-        MatTheme {}
+        MatTheme {
+            theme: Colors::from_seed(palette::PURPLE_500),
+            dark_theme: Some(Colors::from_seed_dark(palette::PURPLE_500)),
+            typography: Typography::with_font_family("Roboto, Helvetica, sans-serif"),
+            shape: Shape::DEFAULT,
+            density: Density::Normal,
+        }
 
         div {
             class: "demo",
@@ -170,6 +206,142 @@ html {{
                     MatButton { label: "secondary" }
                 }
             }
+
+            span {
+                onclick: {
+                    to_owned![dialog_link];
+                    move |_| dialog_link.show()
+                },
+                MatButton { raised: true, label: "linked" }
+            }
+            MatDialog {
+                heading: "Linked",
+                dialog_link: dialog_link.clone(),
+                div { "Opened and closed without any state" }
+                span {
+                    onclick: {
+                        to_owned![dialog_link];
+                        move |_| dialog_link.close()
+                    },
+                    MatButton { label: "close via link" }
+                }
+            }
+        }
+
+        div {
+            class: "demo",
+            span {
+                onclick: move |_| snackbar_open.set(true),
+                MatButton { raised: true, label: "open snackbar" }
+            }
+            MatSnackbar {
+                label_text: "Can't send photo. Retry in 5 seconds.",
+                open: **snackbar_open,
+                _onclosed: {
+                    to_owned![snackbar_open];
+                    move |reason| {
+                        gloo_console::log!(format!("{reason:?}"));
+                        snackbar_open.set(false);
+                    }
+                },
+                MatButton { label: "retry", slot: "action" }
+                MatIconButton { icon: "close", slot: "dismiss" }
+            }
+
+            span {
+                onclick: {
+                    to_owned![snackbar_link];
+                    move |_| snackbar_link.show()
+                },
+                MatButton { raised: true, label: "show leading snackbar" }
+            }
+            MatSnackbar {
+                label_text: "Shown by calling `SnackbarLink::show`",
+                leading: true,
+                snackbar_link: snackbar_link.clone(),
+            }
+        }
+
+        div {
+            class: "demo",
+            MatSelect {
+                label: "Fruit",
+                value: "{select_value}",
+                _onselected: {
+                    to_owned![select_value, select_link];
+                    move |_| select_value.set(select_link.value().unwrap_or_default())
+                },
+                select_link: select_link.clone(),
+                MatListItem { value: "apple", "Apple" }
+                MatListItem { value: "banana", "Banana" }
+                MatListItem { value: "cherry", "Cherry" }
+            }
+            MatSelect {
+                label: "Outlined",
+                outlined: true,
+                required: true,
+                helper: "required",
+                MatListItem { value: "", "" }
+                MatListItem { value: "1", "Option 1" }
+                MatListItem { value: "2", "Option 2" }
+            }
+            span {
+                onclick: {
+                    to_owned![select_link];
+                    move |_| select_link.select(Some(2))
+                },
+                MatButton { label: "select cherry" }
+            }
+            span { "value: {select_value}" }
+        }
+
+        div {
+            class: "demo",
+            MenuDemo {}
+        }
+
+        div {
+            class: "demo",
+            DrawerDemo {}
+        }
+
+        div {
+            class: "demo",
+            TopAppBarDemo {}
+        }
+
+        div {
+            class: "demo",
+            TabsDemo {}
+        }
+
+        div {
+            class: "demo",
+            SliderDemo {}
+        }
+
+        div {
+            class: "demo",
+            IconButtonToggleDemo {}
+        }
+
+        div {
+            class: "demo",
+            FormDemo {}
+        }
+
+        div {
+            class: "demo",
+            ThemeModeDemo {}
+        }
+
+        MatThemeScope {
+            theme: Colors::from_seed_dark(palette::TEAL_500),
+            class: "demo",
+            MatButton { label: "scoped", raised: true, corner_radius: "18px" }
+            MatSwitch { selected: true }
+            MatCheckbox { checked: true }
+            MatTextField { label: "Scoped", outlined: true, corner_radius: "12px" }
         }
 
         div {
@@ -242,6 +414,75 @@ html {{
                 suffix: "suffix",
             }
             MatTextField { label: "Disabled", disabled: true }
+
+            MatTextField {
+                label: "Username",
+                auto_validate: true,
+                validators: Validators::new()
+                    .required("Please enter a username")
+                    .min_length(3, "Must be at least 3 characters long")
+                    .pattern("[a-z0-9_]*", "Only lowercase letters, digits and _ are allowed"),
+                async_validator: AsyncValidator::new(|username| async move {
+                    // simulate a server lookup
+                    TimeoutFuture::new(1000).await;
+                    match ["admin", "root"].contains(&username.as_str()) {
+                        true => Err(format!("{username} is already taken")),
+                        false => Ok(()),
+                    }
+                }),
+            }
+            MatTextField {
+                label: "Email",
+                auto_validate: true,
+                validators: Validators::new().email("Please enter a valid email address"),
+            }
+            MatNumberField::<u8> {
+                label: "Age",
+                min: 0,
+                max: 120,
+                auto_validate: true,
+                validation_message: "Please enter a whole number between 0 and 120",
+                _oninput: move |age: Option<u8>| gloo_console::log!(format!("age: {age:?}")),
+            }
+            MatNumberField::<f64> { label: "Price", step: 0.01, prefix: "$" }
+            MatDateField {
+                label: "Date",
+                min: Date::new(2024, 1, 1).unwrap(),
+                step: 7,
+                _onchange: move |date: Option<Date>| gloo_console::log!(format!("date: {date:?}")),
+            }
+            MatTimeField { label: "Time", value: Time::new(9, 30, 0).unwrap() }
+            MatTextField {
+                label: "Phone",
+                icon: "phone",
+                formatter: Formatter::mask("(###) ###-####"),
+                _onformatted_input: move |value: FormattedValue| {
+                    gloo_console::log!(format!("phone: {} ({})", value.formatted, value.raw))
+                },
+            }
+
+            MatTextField {
+                label: "Required",
+                required: true,
+                validation_message: "This field is required",
+                textfield_link: textfield_link.clone(),
+                _onvalidity_change: {
+                    to_owned![textfield_valid];
+                    move |validity: ValidityState| textfield_valid.set(Some(validity.valid()))
+                },
+            }
+            span {
+                onclick: {
+                    to_owned![textfield_link];
+                    move |_| {
+                        if textfield_link.report_validity() == Some(false) {
+                            textfield_link.focus();
+                        }
+                    }
+                },
+                MatButton { label: "validate" }
+            }
+            span { "valid: {textfield_valid:?}" }
         }
 
         div {
@@ -370,6 +611,16 @@ html {{
                 closed: **circular_progress_closed,
                 indeterminate: true,
             }
+            MatLinearProgress {
+                closed: **circular_progress_closed,
+                progress: **circular_progress_progress,
+                buffer: **circular_progress_progress + 0.2,
+            }
+            MatLinearProgress {
+                closed: **circular_progress_closed,
+                indeterminate: true,
+                reverse: true,
+            }
         }
 
         div {
@@ -457,12 +708,14 @@ fn ListDemo2(cx: Scope) -> Element {
 #[allow(non_snake_case)]
 fn ListDemo3(cx: Scope) -> Element {
     let selected = use_state(cx, HashSet::new);
+    let list_link = cx.use_hook(ListLink::default);
 
     render! {
         div {
             class: "list-demo",
             MatList {
                 multi: true,
+                list_link: list_link.clone(),
                 _onaction: {
                     to_owned![selected];
                     move |val: ListIndex| selected.set(val.unwrap_multi())
@@ -473,6 +726,13 @@ fn ListDemo3(cx: Scope) -> Element {
                 MatCheckListItem { "Item 2", left: true }
                 MatCheckListItem { "Item 3", left: true }
             }
+            span {
+                onclick: {
+                    to_owned![list_link];
+                    move |_| list_link.select(&ListIndex::Multi((0..4).collect()))
+                },
+                MatButton { label: "select all" }
+            }
             code { "selected: {selected:?}" }
         }
     }
@@ -501,3 +761,301 @@ fn ListDemo4(cx: Scope) -> Element {
         }
     }
 }
+
+#[allow(non_snake_case)]
+fn MenuDemo(cx: Scope) -> Element {
+    let open = use_state(cx, || false);
+    let last_action = use_state(cx, || None);
+
+    render! {
+        div {
+            style: "position: relative",
+            id: "menu-demo-anchor",
+            span {
+                onclick: move |_| open.set(true),
+                MatButton { raised: true, label: "open menu" }
+            }
+            MatMenu {
+                open: **open,
+                anchor: "menu-demo-anchor",
+                corner: Corner::BottomStart,
+                _onclosed: {
+                    to_owned![open];
+                    move |_| open.set(false)
+                },
+                _onaction: {
+                    to_owned![last_action];
+                    move |val: ListIndex| last_action.set(val.unwrap_single())
+                },
+                MatListItem { "Item 0" }
+                MatListItem { "Item 1" }
+                MatListItem { "Item 2" }
+            }
+        }
+        code { "last action: {last_action:?}" }
+    }
+}
+
+#[allow(non_snake_case)]
+fn DrawerDemo(cx: Scope) -> Element {
+    let open = use_state(cx, || false);
+
+    render! {
+        div {
+            style: "position: relative; height: 240px; overflow: hidden",
+            MatDrawer {
+                open: **open,
+                has_header: true,
+                drawer_type: DrawerType::Modal,
+                _onclosed: {
+                    to_owned![open];
+                    move |_| open.set(false)
+                },
+                MatDrawerTitle { "Drawer" }
+                MatDrawerSubtitle { "modal" }
+                MatList {
+                    MatListItem { "Item 0" }
+                    MatListItem { "Item 1" }
+                }
+                MatDrawerAppContent {
+                    span {
+                        onclick: move |_| open.set(true),
+                        MatButton { raised: true, label: "open drawer" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+fn TopAppBarDemo(cx: Scope) -> Element {
+    let nav_clicks = use_state(cx, || 0);
+
+    render! {
+        div {
+            id: "top-app-bar-demo-scroll",
+            style: "height: 200px; overflow: auto",
+            MatTopAppBar {
+                center_title: true,
+                scroll_target: "top-app-bar-demo-scroll",
+                _onnavigation_icon_click: {
+                    to_owned![nav_clicks];
+                    move |_| nav_clicks.modify(|n| n + 1)
+                },
+                MatTopAppBarNavigationIcon {
+                    MatIconButton { icon: "menu" }
+                }
+                MatTopAppBarTitle { "Title" }
+                MatTopAppBarActionItems {
+                    MatIconButton { icon: "file_download" }
+                }
+                div {
+                    style: "height: 600px; padding: 8px",
+                    code { "navigation icon clicks: {nav_clicks}" }
+                }
+            }
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+fn TabsDemo(cx: Scope) -> Element {
+    let active_index = use_state(cx, || 1);
+
+    render! {
+        MatTabBar {
+            active_index: **active_index,
+            _onactivated: {
+                to_owned![active_index];
+                move |index| active_index.set(index)
+            },
+            MatTab { label: "Home", icon: "home" }
+            MatTab { label: "Favorites", icon: "favorite", stacked: true }
+            MatTab { label: "Settings", icon: "settings", is_fading_indicator: true }
+        }
+        span {
+            onclick: move |_| active_index.set(0),
+            MatButton { label: "go home" }
+        }
+        code { "active index: {active_index}" }
+    }
+}
+
+#[allow(non_snake_case)]
+fn SliderDemo(cx: Scope) -> Element {
+    let value = use_state(cx, || 20.0);
+    let range = use_state(cx, || RangeValue {
+        start: 20.0,
+        end: 80.0,
+    });
+
+    render! {
+        MatSlider {
+            value: **value,
+            step: 5.0,
+            discrete: true,
+            with_tick_marks: true,
+            _oninput: {
+                to_owned![value];
+                move |val| value.set(val)
+            },
+        }
+        MatSlider {
+            value: **value,
+            max: 200.0,
+            _onchange: {
+                to_owned![value];
+                move |val| value.set(val)
+            },
+        }
+        code { "value: {value}" }
+        MatRangeSlider {
+            value_start: range.start,
+            value_end: range.end,
+            discrete: true,
+            _oninput: {
+                to_owned![range];
+                move |val| range.set(val)
+            },
+        }
+        code { "range: {range.start} - {range.end}" }
+    }
+}
+
+#[allow(non_snake_case)]
+fn IconButtonToggleDemo(cx: Scope) -> Element {
+    let on = use_state(cx, || false);
+
+    render! {
+        MatIconButtonToggle {
+            is_on: **on,
+            icon_on: "favorite",
+            icon_off: "favorite_border",
+            _onchange: {
+                to_owned![on];
+                move |val| on.set(val)
+            },
+        }
+        MatIconButtonToggle {
+            is_on: **on,
+            _onchange: {
+                to_owned![on];
+                move |val| on.set(val)
+            },
+            MatOnIconButtonToggle { MatIcon { "visibility" } }
+            MatOffIconButtonToggle { MatIcon { "visibility_off" } }
+        }
+        code { "on: {on}" }
+    }
+}
+
+#[allow(non_snake_case)]
+fn ThemeModeDemo(cx: Scope) -> Element {
+    let theme_mode = use_theme_mode(cx);
+
+    render! {
+        for mode in [ThemeMode::Light, ThemeMode::Dark, ThemeMode::System] {
+            MatButton {
+                label: mode.as_str(),
+                unelevated: theme_mode.get() == mode,
+                outlined: theme_mode.get() != mode,
+                _onclick: {
+                    to_owned![theme_mode];
+                    move |_| theme_mode.set(mode)
+                },
+            }
+        }
+        code { "mode: {theme_mode.get()}" }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Size {
+    Small,
+    Large,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Signup {
+    name: String,
+    age: Option<u8>,
+    fruit: String,
+    subscribe: bool,
+    notify: bool,
+    size: Size,
+    terms: bool,
+}
+
+#[allow(non_snake_case)]
+fn FormDemo(cx: Scope) -> Element {
+    let form = use_form(cx);
+    let submitted = use_state(cx, String::new);
+
+    render! {
+        MatForm {
+            form: form.clone(),
+            _onsubmit: {
+                let form = form.clone();
+                to_owned![submitted];
+                move |_: std::collections::BTreeMap<String, FieldValue>| {
+                    submitted.set(match form.deserialize::<Signup>() {
+                        Ok(signup) => format!("{signup:?}, entries: {:?}", form.entries()),
+                        Err(err) => format!("error: {err}"),
+                    })
+                }
+            },
+            MatTextField {
+                name: "name",
+                label: "Name",
+                required: true,
+                validation_message: "Please enter your name",
+            }
+            MatTextField { name: "age", label: "Age", field_type: TextFieldType::Number }
+            MatSelect {
+                name: "fruit",
+                label: "Fruit",
+                MatListItem { value: "apple", "Apple" }
+                MatListItem { value: "banana", "Banana" }
+            }
+            MatFormfield { label: "Subscribe", MatCheckbox { name: "subscribe" } }
+            MatFormfield { label: "Notify", MatSwitch { name: "notify", selected: true } }
+            MatFormfield { label: "Small", MatRadio { name: "size", value: "small", checked: true } }
+            MatFormfield { label: "Large", MatRadio { name: "size", value: "large" } }
+            MatTextField { name: "referral", label: "Referral code", disabled: true }
+            MatFormfield {
+                label: "I accept the terms",
+                MatCheckbox { name: "terms", required: true }
+            }
+            MatButton { label: "submit", raised: true, _onclick: {
+                let form = form.clone();
+                move |_| { form.submit(); }
+            } }
+            MatButton { label: "reset", _onclick: {
+                let form = form.clone();
+                move |_| form.reset()
+            } }
+            button { r#type: "reset", "native reset" }
+            MatButton { label: "load", _onclick: {
+                let form = form.clone();
+                move |_| {
+                    form.fill(&Signup {
+                        name: "Jane".to_owned(),
+                        age: Some(42),
+                        fruit: "banana".to_owned(),
+                        subscribe: true,
+                        notify: false,
+                        size: Size::Large,
+                        terms: false,
+                    })
+                    .unwrap()
+                }
+            } }
+        }
+        code {
+            "dirty: {form.is_dirty()}, valid: {form.is_valid()}, name touched: {form.is_touched(\"name\")}"
+        }
+        code { "submitted: {submitted}" }
+    }
+}
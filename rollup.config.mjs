@@ -22,6 +22,7 @@ const COMPONENTS = [
     'radio',
     'select',
     'slider',
+    'slider/slider-range',
     'snackbar',
     'switch',
     'tab-bar',
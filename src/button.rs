@@ -35,12 +35,17 @@ pub struct ButtonProps<'a> {
     pub unelevated: bool,
     #[props(default)]
     pub outlined: bool,
-    #[props(default)]
-    pub dense: bool,
+    /// `None` uses the shared [`Density`](crate::theming::Density) of the
+    /// theme, `Some(false)` keeps the normal density even in a compact theme.
+    pub dense: Option<bool>,
     #[props(default)]
     pub disabled: bool,
     #[props(default)]
     pub trailing_icon: bool,
+    /// Overrides [`Shape::small`](crate::theming::Shape::small) of `MatTheme`,
+    /// e.g. `12px`
+    #[props(into)]
+    pub corner_radius: Option<String>,
 
     #[props(into)]
     // the name cannot start with `on` or dioxus will expect an `EventHandler` which aren't static
@@ -70,6 +75,16 @@ fn render<'a>(cx: Scope<'a, ButtonProps<'a>>) -> Element<'a> {
         }
     }
 
+    let shape = cx
+        .props
+        .corner_radius
+        .as_ref()
+        .filter(|radius| !radius.is_empty())
+        .map(|radius| format!("--mdc-shape-small: {radius}; "))
+        .unwrap_or_default();
+
+    let dense = crate::utils::dense(cx, cx.props.dense);
+
     render! {
         mwc-button {
             id: id,
@@ -80,10 +95,10 @@ fn render<'a>(cx: Scope<'a, ButtonProps<'a>>) -> Element<'a> {
             raised: bool_attr!(cx.props.raised),
             unelevated: bool_attr!(cx.props.unelevated),
             outlined: bool_attr!(cx.props.outlined),
-            dense: bool_attr!(cx.props.dense),
+            dense: bool_attr!(dense),
             trailingIcon: bool_attr!(cx.props.trailing_icon),
 
-            style: string_attr!(cx.props.style),
+            style: "{shape}{cx.props.style}",
             class: string_attr!(cx.props.class),
             slot: optional_string_attr!(cx.props.slot),
             dialogInitialFocus: bool_attr!(cx.props.dialog_initial_focus),
@@ -39,6 +39,12 @@ pub struct CheckboxProps<'a> {
     pub indeterminate: bool,
     #[props(default)]
     pub disabled: bool,
+    /// Whether the checkbox must be checked when a surrounding `MatForm` is
+    /// submitted. Not validated by the checkbox itself.
+    #[props(default)]
+    pub required: bool,
+    #[props(into)]
+    pub name: Option<String>,
     #[props(into)]
     pub value: Option<String>,
     #[props(default)]
@@ -65,10 +71,23 @@ pub struct CheckboxProps<'a> {
 fn render<'a>(cx: Scope<'a, CheckboxProps<'a>>) -> Element<'a> {
     let id = crate::use_id(cx, "checkbox");
     let change_listener = cx.use_hook(|| None);
+    #[cfg(feature = "form")]
+    let form_field = crate::form::field::use_form_field(cx, id, cx.props.name.as_deref(), || {
+        Some(cx.props.checked.into())
+    });
     if let Some(elem) = crate::get_elem_by_id(id) {
         let target = elem.clone();
         let cb = JsValue::from(elem).dyn_into::<Checkbox>().unwrap();
-        cb.set_checked(cx.props.checked);
+        #[cfg(feature = "form")]
+        let form_value = form_field.and_then(|field| {
+            field.bind(&target, &["change"], |elem| {
+                crate::form::field::read_bool(elem, "checked")
+            });
+            field.value().as_bool()
+        });
+        #[cfg(not(feature = "form"))]
+        let form_value = None;
+        cb.set_checked(form_value.unwrap_or(cx.props.checked));
         if let Some(listener) = cx.props._onchange.clone() {
             *change_listener = Some(EventListener::new(&target, "change", move |_| {
                 listener.call(cb.checked())
@@ -81,6 +100,8 @@ fn render<'a>(cx: Scope<'a, CheckboxProps<'a>>) -> Element<'a> {
 
             indeterminate: bool_attr!(cx.props.indeterminate),
             disabled: cx.props.disabled,
+            required: bool_attr!(cx.props.required),
+            name: optional_string_attr!(cx.props.name),
             value: optional_string_attr!(cx.props.value),
             reducedTouchTarget: bool_attr!(cx.props.reduced_touch_target),
 
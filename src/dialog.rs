@@ -7,6 +7,7 @@ use gloo::events::EventListener;
 use wasm_bindgen::prelude::*;
 use web_sys::Node;
 
+use crate::utils::WeakComponentLink;
 use crate::StaticCallback;
 
 #[wasm_bindgen(module = "/build/mwc-dialog.js")]
@@ -33,22 +34,6 @@ extern "C" {
 
 loader_hack!(Dialog);
 
-// /// The `mwc-dialog` component.
-// ///
-// /// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/dialog)
-// ///
-// /// ## Actions
-// ///
-// /// In order to pass actions, [`MatDialogAction`] component should be
-// /// used.
-// pub struct MatDialog {
-//     node_ref: NodeRef,
-//     opening_listener: Option<EventListener>,
-//     opened_listener: Option<EventListener>,
-//     closing_listener: Option<EventListener>,
-//     closed_listener: Option<EventListener>,
-// }
-
 /// Props for [`MatDialog`]
 ///
 /// MWC Documentation:
@@ -95,17 +80,13 @@ pub struct DialogProps<'a> {
     /// See events docs to learn more.
     #[props(into)]
     pub _onclosed: Option<StaticCallback<String>>,
-    // TODO: make methods callable
-    // /// [`WeakComponentLink`] for `MatDialog` which provides the following
-    // /// methods:
-    // /// - ```focus(&self)```
-    // /// - ```blur(&self)```
-    // /// - ```show(&self)```
-    // /// - ```close(&self)```
-    // ///
-    // /// See [`WeakComponentLink`] documentation for more information
-    // #[props(default)]
-    // pub dialog_link: WeakComponentLink<MatDialog>,
+    /// [`DialogLink`] for calling methods of this dialog
+    #[props(default)]
+    pub dialog_link: DialogLink,
+    /// Overrides [`Shape::medium`](crate::theming::Shape::medium) of
+    /// `MatTheme`, e.g. `12px`
+    #[props(into)]
+    pub corner_radius: Option<String>,
     pub children: Element<'a>,
 
     #[props(into, default)]
@@ -120,6 +101,7 @@ fn render<'a>(cx: Scope<'a, DialogProps<'a>>) -> Element<'a> {
     let opened_listener = cx.use_hook(|| None);
     let closing_listener = cx.use_hook(|| None);
     let closed_listener = cx.use_hook(|| None);
+    cx.props.dialog_link.0.set_id(id);
     if let Some(elem) = crate::get_elem_by_id(id) {
         let target = elem;
         if let Some(listener) = cx.props._onopening.clone() {
@@ -143,6 +125,13 @@ fn render<'a>(cx: Scope<'a, DialogProps<'a>>) -> Element<'a> {
             }));
         }
     }
+    let shape = cx
+        .props
+        .corner_radius
+        .as_ref()
+        .filter(|radius| !radius.is_empty())
+        .map(|radius| format!("--mdc-shape-medium: {radius}; "))
+        .unwrap_or_default();
     render! {
         mwc-dialog {
             id: id,
@@ -157,7 +146,7 @@ fn render<'a>(cx: Scope<'a, DialogProps<'a>>) -> Element<'a> {
             actionAttribute: optional_string_attr!(cx.props.action_attribute),
             initialFocusAttribute: optional_string_attr!(cx.props.initial_focus_attribute),
 
-            style: "position: absolute; {cx.props.style}",
+            style: "position: absolute; {shape}{cx.props.style}",
             class: string_attr!(cx.props.class),
 
             &cx.props.children
@@ -167,6 +156,53 @@ fn render<'a>(cx: Scope<'a, DialogProps<'a>>) -> Element<'a> {
 
 component!('a, MatDialog, DialogProps, render, Dialog, "dialog");
 
+/// Link to a rendered [`MatDialog`], passed to it via
+/// [`DialogProps::dialog_link`].
+///
+/// The link should be created once per component, e.g. with
+/// `cx.use_hook(DialogLink::default)`. Calling methods while the dialog is
+/// not mounted has no effect.
+#[derive(Clone, Default, PartialEq)]
+pub struct DialogLink(WeakComponentLink);
+
+impl DialogLink {
+    /// Binds to `show` method.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/dialog#methods) for details
+    pub fn show(&self) {
+        if let Some(dialog) = self.0.cast::<Dialog>() {
+            dialog.show();
+        }
+    }
+
+    /// Binds to `close` method.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/dialog#methods) for details
+    pub fn close(&self) {
+        if let Some(dialog) = self.0.cast::<Dialog>() {
+            dialog.close();
+        }
+    }
+
+    /// Binds to `focus` method.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/dialog#methods) for details
+    pub fn focus(&self) {
+        if let Some(dialog) = self.0.cast::<Dialog>() {
+            dialog.focus();
+        }
+    }
+
+    /// Binds to `blur` method.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/dialog#methods) for details
+    pub fn blur(&self) {
+        if let Some(dialog) = self.0.cast::<Dialog>() {
+            dialog.blur();
+        }
+    }
+}
+
 #[wasm_bindgen]
 extern "C" {
     type DialogActionType;
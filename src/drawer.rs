@@ -2,18 +2,20 @@ mod drawer_app_content;
 mod drawer_header;
 mod drawer_subtitle;
 mod drawer_title;
+mod drawer_type;
 
 pub use drawer_app_content::*;
 pub use drawer_header::*;
 pub use drawer_subtitle::*;
 pub use drawer_title::*;
+pub use drawer_type::DrawerType;
 
-use crate::{bool_to_option, WeakComponentLink};
+use dioxus::prelude::*;
 use gloo::events::EventListener;
 use wasm_bindgen::prelude::*;
 use web_sys::Node;
-use yew::prelude::*;
-use yew::virtual_dom::AttrValue;
+
+use crate::StaticCallback;
 
 #[wasm_bindgen(module = "/build/mwc-drawer.js")]
 extern "C" {
@@ -24,9 +26,6 @@ extern "C" {
     #[wasm_bindgen(getter, static_method_of = Drawer)]
     fn _dummy_loader() -> JsValue;
 
-    #[wasm_bindgen(method, getter)]
-    fn open(this: &Drawer) -> bool;
-
     #[wasm_bindgen(method, setter)]
     fn set_open(this: &Drawer, value: bool);
 
@@ -36,119 +35,79 @@ extern "C" {
 
 loader_hack!(Drawer);
 
-/// The `mwc-drawer` component
-///
-/// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/drawer)
-pub struct MatDrawer {
-    node_ref: NodeRef,
-    opened_listener: Option<EventListener>,
-    closed_listener: Option<EventListener>,
-}
-
 /// Props for [`MatDrawer`]
 ///
 /// MWC Documentation:
 ///
 /// - [Properties](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/drawer#propertiesattributes)
 /// - [Events](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/drawer#events)
-#[derive(Properties, PartialEq, Clone)]
-pub struct DrawerProps {
-    #[prop_or_default]
+#[derive(Props)]
+pub struct DrawerProps<'a> {
+    /// Whether the drawer is open. This is applied on every render, so
+    /// `_onclosed` should be used to keep it in sync when the drawer is closed
+    /// by the user, e.g. by clicking the scrim of a modal drawer.
+    #[props(default)]
     pub open: bool,
-    #[prop_or_default]
+    #[props(default)]
     pub has_header: bool,
-    #[prop_or_default]
-    pub drawer_type: Option<AttrValue>,
-    /// Binds to `opened` event on `mwc-drawer`
+    /// `None` results in a standard, permanently visible drawer
+    #[props(default)]
+    pub drawer_type: Option<DrawerType>,
+    /// Binds to `MDCDrawer:opened` event on `mwc-drawer`
     ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub onopened: Callback<()>,
-    /// Binds to `closed` event on `mwc-drawer`
+    #[props(into)]
+    // the name cannot start with `on` or dioxus will expect an `EventHandler` which aren't static
+    // and thus cannot be used here
+    pub _onopened: Option<StaticCallback<()>>,
+    /// Binds to `MDCDrawer:closed` event on `mwc-drawer`
     ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub onclosed: Callback<()>,
-    #[prop_or_default]
-    pub drawer_link: WeakComponentLink<MatDrawer>,
-    pub children: Children,
+    #[props(into)]
+    pub _onclosed: Option<StaticCallback<()>>,
+    pub children: Element<'a>,
+
+    #[props(into, default)]
+    pub style: String,
+    #[props(into, default)]
+    pub class: String,
 }
 
-impl Component for MatDrawer {
-    type Message = ();
-    type Properties = DrawerProps;
-
-    fn create(ctx: &Context<Self>) -> Self {
-        ctx.props()
-            .drawer_link
-            .borrow_mut()
-            .replace(ctx.link().clone());
-        Drawer::ensure_loaded();
-        Self {
-            node_ref: NodeRef::default(),
-            opened_listener: None,
-            closed_listener: None,
+fn render<'a>(cx: Scope<'a, DrawerProps<'a>>) -> Element<'a> {
+    let id = crate::use_id(cx, "drawer");
+    let opened_listener = cx.use_hook(|| None);
+    let closed_listener = cx.use_hook(|| None);
+    if let Some(elem) = crate::get_elem_by_id(id) {
+        let target = elem.clone();
+        let drawer = JsValue::from(elem).dyn_into::<Drawer>().unwrap();
+        drawer.set_type(&JsValue::from_str(
+            cx.props.drawer_type.map(|t| t.as_str()).unwrap_or_default(),
+        ));
+        drawer.set_open(cx.props.open);
+        if let Some(listener) = cx.props._onopened.clone() {
+            *opened_listener = Some(EventListener::new(&target, "MDCDrawer:opened", move |_| {
+                listener.call(())
+            }));
+        }
+        if let Some(listener) = cx.props._onclosed.clone() {
+            *closed_listener = Some(EventListener::new(&target, "MDCDrawer:closed", move |_| {
+                listener.call(())
+            }));
         }
     }
 
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props();
-        html! {
-        <mwc-drawer hasHeader={bool_to_option(props.has_header)} ref={self.node_ref.clone()}>
-            {props.children.clone()}
-        </mwc-drawer>
-               }
-    }
+    render! {
+        mwc-drawer {
+            id: id,
 
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        let props = ctx.props();
-        let element = self.node_ref.cast::<Drawer>().unwrap();
-        element.set_type(&JsValue::from(
-            props
-                .drawer_type
-                .as_ref()
-                .map(|s| s.as_ref())
-                .unwrap_or_default(),
-        ));
-        element.set_open(props.open);
-
-        if self.opened_listener.is_none() {
-            let onopen_callback = props.onopened.clone();
-            self.opened_listener = Some(EventListener::new(
-                &element,
-                "MDCDrawer:opened",
-                move |_| {
-                    onopen_callback.emit(());
-                },
-            ));
-        }
+            hasHeader: bool_attr!(cx.props.has_header),
 
-        if self.closed_listener.is_none() {
-            let onclose_callback = props.onclosed.clone();
-            self.closed_listener = Some(EventListener::new(
-                &element,
-                "MDCDrawer:closed",
-                move |_| {
-                    onclose_callback.emit(());
-                },
-            ));
+            style: string_attr!(cx.props.style),
+            class: string_attr!(cx.props.class),
+
+            &cx.props.children
         }
     }
 }
 
-impl WeakComponentLink<MatDrawer> {
-    /// A convenience method to for `drawer.open = !drawer.open`
-    pub fn flip_open_state(&self) {
-        let node_ref = self
-            .borrow()
-            .as_ref()
-            .unwrap()
-            .get_component()
-            .unwrap()
-            .node_ref
-            .clone();
-        let element = node_ref.cast::<Drawer>().unwrap();
-        let open = element.open();
-        element.set_open(!open);
-    }
-}
+component!('a, MatDrawer, DrawerProps, render, Drawer, "drawer");
@@ -1,50 +1,25 @@
-use yew::prelude::*;
+use dioxus::prelude::*;
 
 const SLOT: &str = "title";
 
 /// Props for [`MatDrawerTitle`]
-#[derive(Properties, PartialEq, Clone)]
-pub struct DrawerTitleProps {
-    pub children: Children,
+#[derive(Props)]
+pub struct DrawerTitleProps<'a> {
+    pub children: Element<'a>,
 }
 
 /// Defines title for [`MatDrawer`][crate::MatDrawer].
 ///
-/// If the child passed is an element (a `VTag`), then it is modified to include
-/// the appropriate attributes. Otherwise, the child is wrapped in a `span`
-/// containing said attributes.
-pub struct MatDrawerTitle {}
-
-impl Component for MatDrawerTitle {
-    type Message = ();
-    type Properties = DrawerTitleProps;
-
-    fn create(_: &Context<Self>) -> Self {
-        Self {}
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props();
-        let children = props
-            .children
-            .iter()
-            .map(|child| match child {
-                Html::VTag(mut vtag) => {
-                    vtag.add_attribute("slot", "title");
-                    Html::VTag(vtag)
-                }
-                _ => {
-                    html! {
-                         <span slot={SLOT}>
-                             {child}
-                         </span>
-                    }
-                }
-            })
-            .collect::<Html>();
-
-        html! {
-             {children}
+/// Only shown if [`DrawerProps::has_header`][crate::drawer::DrawerProps::has_header] is set.
+///
+/// The passed children are wrapped in a `span` with the required attributes
+/// set.
+#[allow(non_snake_case)]
+pub fn MatDrawerTitle<'a>(cx: Scope<'a, DrawerTitleProps<'a>>) -> Element<'a> {
+    render! {
+        span {
+            slot: SLOT,
+            &cx.props.children
         }
     }
 }
@@ -0,0 +1,26 @@
+use std::fmt;
+
+/// Type for [`DrawerProps::drawer_type`][super::DrawerProps::drawer_type].
+///
+/// Equivalent to `type DrawerType = 'dismissible'|'modal'|'';` Typescript
+/// type, where `''` is represented by `None` on the prop.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DrawerType {
+    Dismissible,
+    Modal,
+}
+
+impl DrawerType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DrawerType::Dismissible => "dismissible",
+            DrawerType::Modal => "modal",
+        }
+    }
+}
+
+impl fmt::Display for DrawerType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,402 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use dioxus::prelude::*;
+use wasm_bindgen::prelude::*;
+
+use web_sys::FormData;
+
+use crate::text_inputs::{NativeValidityState, ValidityState};
+use crate::StaticCallback;
+
+// only used by the enabled input components
+#[allow(dead_code)]
+pub(crate) mod field;
+
+mod native;
+
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(feature = "serde")]
+pub use serialization::FormError;
+
+/// The value of a single form field.
+///
+/// Text inputs, selects and radio groups hold [`FieldValue::Text`], checkboxes
+/// and switches hold [`FieldValue::Bool`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    Text(String),
+    Bool(bool),
+}
+
+impl FieldValue {
+    /// Returns the text, or `None` for [`FieldValue::Bool`].
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            FieldValue::Text(text) => Some(text),
+            FieldValue::Bool(_) => None,
+        }
+    }
+
+    /// Returns the boolean, or `None` for [`FieldValue::Text`].
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            FieldValue::Bool(value) => Some(*value),
+            FieldValue::Text(_) => None,
+        }
+    }
+}
+
+impl Default for FieldValue {
+    fn default() -> Self {
+        FieldValue::Text(String::new())
+    }
+}
+
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldValue::Text(text) => write!(f, "{text}"),
+            FieldValue::Bool(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<String> for FieldValue {
+    fn from(text: String) -> Self {
+        FieldValue::Text(text)
+    }
+}
+
+impl From<&str> for FieldValue {
+    fn from(text: &str) -> Self {
+        FieldValue::Text(text.to_owned())
+    }
+}
+
+impl From<bool> for FieldValue {
+    fn from(value: bool) -> Self {
+        FieldValue::Bool(value)
+    }
+}
+
+#[derive(Default)]
+struct FieldState {
+    value: FieldValue,
+    initial: FieldValue,
+    touched: bool,
+    validity: Option<ValidityState>,
+    /// Conversion error set by `Form::deserialize`
+    error: Option<String>,
+    /// `id`s of the rendered elements, more than one for radio groups
+    ids: Vec<String>,
+}
+
+#[derive(Default)]
+struct FormState {
+    fields: BTreeMap<String, FieldState>,
+    /// `id` of the rendered `<form>`
+    element_id: Option<String>,
+    onsubmit: Option<StaticCallback<BTreeMap<String, FieldValue>>>,
+    onreset: Option<StaticCallback<()>>,
+}
+
+/// Handle to the state of a [`MatForm`], created with [`use_form`].
+///
+/// Inputs inside the form register themselves by their `name` prop. For
+/// registered inputs the form owns the value: the `value`/`checked`/`selected`
+/// props are only used as the initial value.
+#[derive(Clone)]
+pub struct Form {
+    state: Rc<RefCell<FormState>>,
+    update: Arc<dyn Fn()>,
+}
+
+impl PartialEq for Form {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+/// Creates a [`Form`] which rerenders the calling component whenever a value
+/// or flag changes. Pass it to a [`MatForm`] to connect the inputs inside.
+pub fn use_form(cx: &ScopeState) -> &Form {
+    cx.use_hook(|| Form {
+        state: Rc::default(),
+        update: cx.schedule_update(),
+    })
+}
+
+impl Form {
+    /// The current value of the field `name`.
+    pub fn value(&self, name: &str) -> Option<FieldValue> {
+        self.state
+            .borrow()
+            .fields
+            .get(name)
+            .map(|field| field.value.clone())
+    }
+
+    /// The current values of all registered fields.
+    pub fn values(&self) -> BTreeMap<String, FieldValue> {
+        self.state
+            .borrow()
+            .fields
+            .iter()
+            .map(|(name, field)| (name.clone(), field.value.clone()))
+            .collect()
+    }
+
+    /// Sets the value of the field `name`, registering it if needed.
+    pub fn set_value(&self, name: &str, value: impl Into<FieldValue>) {
+        self.state
+            .borrow_mut()
+            .fields
+            .entry(name.to_owned())
+            .or_default()
+            .value = value.into();
+        (self.update)();
+    }
+
+    /// Whether any field differs from its initial value.
+    pub fn is_dirty(&self) -> bool {
+        self.state
+            .borrow()
+            .fields
+            .values()
+            .any(|field| field.value != field.initial)
+    }
+
+    /// Whether the field `name` differs from its initial value.
+    pub fn is_field_dirty(&self, name: &str) -> bool {
+        self.state
+            .borrow()
+            .fields
+            .get(name)
+            .is_some_and(|field| field.value != field.initial)
+    }
+
+    /// Whether the user has interacted with the field `name`. All fields are
+    /// marked as touched by [`Form::submit`].
+    pub fn is_touched(&self, name: &str) -> bool {
+        self.state
+            .borrow()
+            .fields
+            .get(name)
+            .is_some_and(|field| field.touched)
+    }
+
+    /// The last known validity of the field `name`. `None` if the field has
+    /// not been checked yet or has no validation.
+    pub fn validity(&self, name: &str) -> Option<ValidityState> {
+        self.state
+            .borrow()
+            .fields
+            .get(name)
+            .and_then(|field| field.validity)
+    }
+
+    /// Whether no field is known to be invalid.
+    // `Option::is_none_or` needs Rust 1.82
+    #[allow(clippy::unnecessary_map_or)]
+    pub fn is_valid(&self) -> bool {
+        self.state
+            .borrow()
+            .fields
+            .values()
+            .all(|field| field.validity.map_or(true, |validity| validity.valid()))
+    }
+
+    /// Reports the validity of all inputs inside the [`MatForm`], showing
+    /// their validation messages, and marks all fields as touched. If all
+    /// inputs are valid, the `_onsubmit` callback of the [`MatForm`] is called
+    /// with the current values.
+    ///
+    /// Disabled inputs are not validated. Checkboxes, radios and switches with
+    /// the `required` prop must be checked, for radios any radio of the group.
+    ///
+    /// Returns whether all inputs are valid.
+    pub fn submit(&self) -> bool {
+        let controls = self.controls();
+        let mut valid = true;
+        for elem in &controls {
+            let Some(validity) = native::check(elem, &controls) else {
+                continue;
+            };
+            valid &= validity.valid();
+            let Some(name) = native::name(elem) else {
+                continue;
+            };
+            if let Some(field) = self.state.borrow_mut().fields.get_mut(&name) {
+                field.validity = Some(validity);
+            }
+        }
+        let onsubmit = {
+            let mut state = self.state.borrow_mut();
+            state
+                .fields
+                .values_mut()
+                .for_each(|field| field.touched = true);
+            state.onsubmit.clone()
+        };
+        (self.update)();
+        if let (true, Some(onsubmit)) = (valid, onsubmit) {
+            onsubmit.call(self.values());
+        }
+        valid
+    }
+
+    /// Resets all fields to their initial values and clears the touched flags
+    /// and validity. Then the `_onreset` callback of the [`MatForm`] is
+    /// called.
+    pub fn reset(&self) {
+        for field in self.state.borrow_mut().fields.values_mut() {
+            field.value = field.initial.clone();
+            field.touched = false;
+            field.validity = None;
+            if field.error.take().is_some() {
+                for elem in field.ids.iter().filter_map(|id| crate::get_elem_by_id(id)) {
+                    set_custom_validity(&elem, "");
+                }
+            }
+        }
+        (self.update)();
+        let onreset = self.state.borrow().onreset.clone();
+        if let Some(onreset) = onreset {
+            onreset.call(());
+        }
+    }
+
+    /// The name/value pairs of all Material inputs inside the [`MatForm`], in
+    /// document order, like a native form submission would collect them.
+    ///
+    /// Inputs without a name and disabled inputs are left out, just like
+    /// unchecked checkboxes, radios and switches. Checked ones without a
+    /// `value` are submitted as `"on"`. Unlike [`Form::values`], this includes
+    /// inputs that are not registered as fields.
+    pub fn entries(&self) -> Vec<(String, String)> {
+        self.controls().iter().filter_map(native::entry).collect()
+    }
+
+    /// The [`Form::entries`] as [`FormData`], e.g. for sending them with
+    /// `fetch`. `None` if the [`MatForm`] is not mounted.
+    pub fn form_data(&self) -> Option<FormData> {
+        self.element()?;
+        let data = FormData::new().ok()?;
+        for (name, value) in self.entries() {
+            data.append_with_str(&name, &value).ok()?;
+        }
+        Some(data)
+    }
+
+    fn element(&self) -> Option<web_sys::Element> {
+        let state = self.state.borrow();
+        crate::get_elem_by_id(state.element_id.as_deref()?)
+    }
+
+    /// All Material inputs inside the rendered [`MatForm`].
+    fn controls(&self) -> Vec<web_sys::Element> {
+        self.element()
+            .map(|form| native::controls(&form))
+            .unwrap_or_default()
+    }
+}
+
+/// Props for [`MatForm`]
+#[derive(Props)]
+pub struct FormProps<'a> {
+    /// The [`Form`] created with [`use_form`]
+    pub form: Form,
+    /// Called by [`Form::submit`] with the current values if all fields are
+    /// valid
+    #[props(into)]
+    // the name cannot start with `on` or dioxus will expect an `EventHandler` which aren't static
+    // and thus cannot be used here
+    pub _onsubmit: Option<StaticCallback<BTreeMap<String, FieldValue>>>,
+    /// Called by [`Form::reset`], which is also called on the `reset` event
+    /// of the `<form>`, e.g. from a `<button type="reset">`
+    #[props(into)]
+    pub _onreset: Option<StaticCallback<()>>,
+    pub children: Element<'a>,
+
+    #[props(into, default)]
+    pub style: String,
+    #[props(into, default)]
+    pub class: String,
+}
+
+/// Connects the inputs inside it to a [`Form`].
+///
+/// Renders a `<form>` which calls [`Form::submit`] on its `submit` event,
+/// instead of the native submission, and [`Form::reset`] on its `reset`
+/// event.
+///
+/// Every [`MatTextField`](crate::MatTextField),
+/// [`MatTextArea`](crate::MatTextArea), [`MatSelect`](crate::MatSelect),
+/// [`MatCheckbox`](crate::MatCheckbox), [`MatRadio`](crate::MatRadio) and
+/// [`MatSwitch`](crate::MatSwitch) with a `name` is registered as a field.
+/// Radios with the same `name` form one field holding the `value` of the
+/// checked radio.
+#[allow(non_snake_case)]
+pub fn MatForm<'a>(cx: Scope<'a, FormProps<'a>>) -> Element<'a> {
+    let id = crate::use_id(cx, "form");
+    let form = cx.provide_context(cx.props.form.clone());
+    {
+        let mut state = form.state.borrow_mut();
+        state.element_id = Some(id.to_owned());
+        state.onsubmit = cx.props._onsubmit.clone();
+        state.onreset = cx.props._onreset.clone();
+    }
+    let reset_form = form.clone();
+
+    render! {
+        form {
+            id: id,
+            novalidate: true,
+            prevent_default: "onsubmit",
+            onsubmit: move |_| {
+                form.submit();
+            },
+            onreset: move |_| reset_form.reset(),
+
+            style: string_attr!(cx.props.style),
+            class: string_attr!(cx.props.class),
+
+            &cx.props.children
+        }
+    }
+}
+
+/// Calls `reportValidity` on `elem`, `None` if it has no such method.
+fn report_validity(elem: &web_sys::Element) -> Option<bool> {
+    js_sys::Reflect::get(elem, &JsValue::from_str("reportValidity"))
+        .ok()?
+        .dyn_into::<js_sys::Function>()
+        .ok()?
+        .call0(elem)
+        .ok()?
+        .as_bool()
+}
+
+/// Calls `setCustomValidity` on `elem` if it has such a method.
+fn set_custom_validity(elem: &web_sys::Element, message: &str) {
+    if let Some(function) = js_sys::Reflect::get(elem, &JsValue::from_str("setCustomValidity"))
+        .ok()
+        .and_then(|function| function.dyn_into::<js_sys::Function>().ok())
+    {
+        function.call1(elem, &JsValue::from_str(message)).ok();
+    }
+}
+
+/// Reads the `validity` property of `elem`, `None` if it has none.
+pub(super) fn element_validity(elem: &web_sys::Element) -> Option<ValidityState> {
+    let validity = js_sys::Reflect::get(elem, &JsValue::from_str("validity")).ok()?;
+    if validity.is_undefined() || validity.is_null() {
+        return None;
+    }
+    Some(validity.unchecked_into::<NativeValidityState>().into())
+}
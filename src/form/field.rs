@@ -0,0 +1,156 @@
+use std::cell::RefCell;
+
+use dioxus::prelude::*;
+use gloo::events::EventListener;
+use wasm_bindgen::prelude::*;
+
+use super::{element_validity, FieldValue, Form};
+
+impl Form {
+    fn register(&self, name: &str, id: &str, initial: Option<FieldValue>) {
+        if let Some(initial) = initial {
+            self.set_initial(name, initial);
+        }
+        let mut state = self.state.borrow_mut();
+        let field = state.fields.entry(name.to_owned()).or_default();
+        field.ids.push(id.to_owned());
+    }
+
+    /// Sets the initial value of the field `name`. The current value is only
+    /// replaced if the field is not dirty.
+    fn set_initial(&self, name: &str, initial: FieldValue) {
+        let mut state = self.state.borrow_mut();
+        let field = state.fields.entry(name.to_owned()).or_default();
+        if field.value == field.initial {
+            field.value = initial.clone();
+        }
+        field.initial = initial;
+    }
+}
+
+/// Registration of a rendered input in the surrounding [`MatForm`](super::MatForm).
+///
+/// The element `id` is removed from the form again when the input is
+/// unmounted, the value is kept.
+pub(crate) struct FormField {
+    form: Form,
+    name: String,
+    id: String,
+    /// Whether the initial value is read from the element when binding
+    adopt_initial: bool,
+    listeners: RefCell<Vec<EventListener>>,
+}
+
+/// Registers the input with the `id` in the surrounding [`MatForm`](super::MatForm) as field
+/// `name`. Returns `None` if there is no form or no name. `initial` is only
+/// called once. If it returns `None`, the initial value is read from the
+/// element by [`FormField::bind`] instead.
+pub(crate) fn use_form_field<'a>(
+    cx: &'a ScopeState,
+    id: &str,
+    name: Option<&str>,
+    initial: impl FnOnce() -> Option<FieldValue>,
+) -> Option<&'a FormField> {
+    cx.use_hook(|| {
+        let form = cx.consume_context::<Form>()?;
+        let name = name?.to_owned();
+        let initial = initial();
+        let adopt_initial = initial.is_none();
+        form.register(&name, id, initial);
+        Some(FormField {
+            form,
+            name,
+            id: id.to_owned(),
+            adopt_initial,
+            listeners: RefCell::default(),
+        })
+    })
+    .as_ref()
+}
+
+impl FormField {
+    /// The current value of this field.
+    pub(crate) fn value(&self) -> FieldValue {
+        self.form.value(&self.name).unwrap_or_default()
+    }
+
+    /// Updates the form from `target` after each of `events`, using `read` to
+    /// get the new value. `None` leaves the value unchanged, e.g. for an
+    /// unchecked radio. Listeners are only added once.
+    ///
+    /// Must be called before [`FormField::value`] in the same render.
+    pub(crate) fn bind(
+        &self,
+        target: &web_sys::Element,
+        events: &[&'static str],
+        read: fn(&web_sys::Element) -> Option<FieldValue>,
+    ) {
+        let mut listeners = self.listeners.borrow_mut();
+        if !listeners.is_empty() {
+            return;
+        }
+        if let (true, Some(initial)) = (self.adopt_initial, read(target)) {
+            self.form.set_initial(&self.name, initial);
+        }
+        for &event in events {
+            let form = self.form.clone();
+            let name = self.name.clone();
+            let elem = target.clone();
+            listeners.push(EventListener::new(target, event, move |_| {
+                // the state must not be borrowed while reading the element,
+                // validators may call `Form::value` from the validity check
+                let value = read(&elem);
+                let had_error = {
+                    let mut state = form.state.borrow_mut();
+                    let Some(field) = state.fields.get_mut(&name) else {
+                        return;
+                    };
+                    if let Some(value) = value {
+                        field.value = value;
+                    }
+                    field.error.take().is_some()
+                };
+                if had_error {
+                    super::set_custom_validity(&elem, "");
+                }
+                let validity = element_validity(&elem);
+                if let Some(field) = form.state.borrow_mut().fields.get_mut(&name) {
+                    field.validity = validity.or(field.validity);
+                }
+                (form.update)();
+            }));
+        }
+        let form = self.form.clone();
+        let name = self.name.clone();
+        listeners.push(EventListener::new(target, "focusout", move |_| {
+            if let Some(field) = form.state.borrow_mut().fields.get_mut(&name) {
+                field.touched = true;
+            }
+            (form.update)();
+        }));
+    }
+}
+
+impl Drop for FormField {
+    fn drop(&mut self) {
+        if let Some(field) = self.form.state.borrow_mut().fields.get_mut(&self.name) {
+            field.ids.retain(|id| id != &self.id);
+        }
+    }
+}
+
+/// Reads the `value` property of `elem` as text.
+pub(crate) fn read_text(elem: &web_sys::Element) -> Option<FieldValue> {
+    js_sys::Reflect::get(elem, &JsValue::from_str("value"))
+        .ok()
+        .and_then(|value| value.as_string())
+        .map(FieldValue::Text)
+}
+
+/// Reads the boolean property `name` of `elem`.
+pub(crate) fn read_bool(elem: &web_sys::Element, name: &str) -> Option<FieldValue> {
+    js_sys::Reflect::get(elem, &JsValue::from_str(name))
+        .ok()
+        .and_then(|value| value.as_bool())
+        .map(FieldValue::Bool)
+}
@@ -0,0 +1,103 @@
+use wasm_bindgen::prelude::*;
+use web_sys::Element;
+
+use super::{element_validity, report_validity};
+use crate::text_inputs::ValidityState;
+
+/// Selector for all Material inputs that take part in a form
+const CONTROLS: &str =
+    "mwc-textfield, mwc-textarea, mwc-select, mwc-checkbox, mwc-radio, mwc-switch";
+
+/// All Material inputs inside `form`, in document order.
+pub(super) fn controls(form: &Element) -> Vec<Element> {
+    let Ok(nodes) = form.query_selector_all(CONTROLS) else {
+        return vec![];
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.get(index)?.dyn_into::<Element>().ok())
+        .collect()
+}
+
+/// The non-empty `name` attribute of `elem`.
+pub(super) fn name(elem: &Element) -> Option<String> {
+    elem.get_attribute("name").filter(|name| !name.is_empty())
+}
+
+fn property(elem: &Element, name: &str) -> JsValue {
+    js_sys::Reflect::get(elem, &JsValue::from_str(name)).unwrap_or(JsValue::UNDEFINED)
+}
+
+fn is_disabled(elem: &Element) -> bool {
+    property(elem, "disabled").as_bool().unwrap_or_default()
+}
+
+fn is_toggle(elem: &Element) -> bool {
+    matches!(
+        elem.local_name().as_str(),
+        "mwc-checkbox" | "mwc-radio" | "mwc-switch"
+    )
+}
+
+fn is_checked(elem: &Element) -> bool {
+    let name = match elem.local_name().as_str() {
+        "mwc-switch" => "selected",
+        _ => "checked",
+    };
+    property(elem, name).as_bool().unwrap_or_default()
+}
+
+/// The submitted name/value pair of `elem`, like for a native `<input>`.
+/// Disabled inputs, inputs without a name and unchecked checkboxes, radios and
+/// switches are left out. Checked ones without a value submit `"on"`.
+pub(super) fn entry(elem: &Element) -> Option<(String, String)> {
+    if is_disabled(elem) {
+        return None;
+    }
+    let name = name(elem)?;
+    let value = property(elem, "value").as_string().unwrap_or_default();
+    if !is_toggle(elem) {
+        return Some((name, value));
+    }
+    if !is_checked(elem) {
+        return None;
+    }
+    match value.is_empty() {
+        true => Some((name, "on".to_owned())),
+        false => Some((name, value)),
+    }
+}
+
+/// Checks the validity of `elem`, showing the validation message of text
+/// inputs. `controls` are all inputs of the form, needed for radio groups.
+///
+/// Returns `None` for disabled inputs, which are not validated.
+pub(super) fn check(elem: &Element, controls: &[Element]) -> Option<ValidityState> {
+    if is_disabled(elem) {
+        return None;
+    }
+    if !is_toggle(elem) {
+        let reported = report_validity(elem).unwrap_or(true);
+        let mut validity = element_validity(elem).unwrap_or_default();
+        if !reported {
+            validity.set_valid(false);
+        }
+        return Some(validity);
+    }
+    let mut validity = ValidityState::new();
+    if !elem.has_attribute("required") {
+        return Some(validity);
+    }
+    let satisfied = match (elem.local_name().as_str(), name(elem)) {
+        // a required radio is satisfied by any checked radio of its group
+        ("mwc-radio", Some(group)) => controls.iter().any(|other| {
+            other.local_name() == "mwc-radio"
+                && name(other).as_ref() == Some(&group)
+                && is_checked(other)
+        }),
+        _ => is_checked(elem),
+    };
+    if !satisfied {
+        validity.set_value_missing(true).set_valid(false);
+    }
+    Some(validity)
+}
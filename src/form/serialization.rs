@@ -0,0 +1,517 @@
+use std::fmt;
+
+use serde::de::value::{MapDeserializer, StrDeserializer};
+use serde::de::{self, DeserializeOwned, IntoDeserializer, Unexpected, Visitor};
+use serde::{forward_to_deserialize_any, Serialize};
+use serde_json::Value;
+
+use super::{FieldValue, Form};
+use crate::text_inputs::ValidityState;
+
+/// Error of [`Form::fill`] and [`Form::deserialize`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormError {
+    field: Option<String>,
+    message: String,
+}
+
+impl FormError {
+    /// The name of the field that caused the error, if it can be attributed
+    /// to one.
+    pub fn field(&self) -> Option<&str> {
+        self.field.as_deref()
+    }
+
+    /// The error message without the field name.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    fn in_field(mut self, name: &str) -> Self {
+        self.field.get_or_insert_with(|| name.to_owned());
+        self
+    }
+}
+
+impl fmt::Display for FormError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.field {
+            Some(field) => write!(f, "field `{field}`: {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for FormError {}
+
+impl de::Error for FormError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        FormError {
+            field: None,
+            message: msg.to_string(),
+        }
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        FormError {
+            field: Some(field.to_owned()),
+            message: "missing field".to_owned(),
+        }
+    }
+}
+
+impl Form {
+    /// Fills the fields with the values of a struct or map.
+    ///
+    /// Strings become text, numbers are formatted as text, booleans are used
+    /// for checkboxes and switches and `None` clears a field: text becomes
+    /// empty and checkboxes and switches unchecked. `None` for a field that
+    /// has not been registered yet leaves it unset. The filled values
+    /// also become the initial values, so [`Form::reset`] restores them and
+    /// [`Form::is_dirty`] compares against them.
+    pub fn fill<T: Serialize + ?Sized>(&self, value: &T) -> Result<(), FormError> {
+        let Value::Object(entries) =
+            serde_json::to_value(value).map_err(<FormError as de::Error>::custom)?
+        else {
+            return Err(de::Error::custom("expected a struct or map"));
+        };
+        let mut values = Vec::with_capacity(entries.len());
+        for (name, value) in entries {
+            // `None` for a null, which clears the field depending on its kind
+            let value = match value {
+                Value::Null => None,
+                Value::Bool(value) => Some(FieldValue::Bool(value)),
+                Value::Number(number) => Some(FieldValue::Text(number.to_string())),
+                Value::String(text) => Some(FieldValue::Text(text)),
+                Value::Array(_) | Value::Object(_) => {
+                    return Err(FormError {
+                        field: Some(name),
+                        message: "nested values are not supported".to_owned(),
+                    })
+                }
+            };
+            values.push((name, value));
+        }
+        {
+            let mut state = self.state.borrow_mut();
+            for (name, value) in values {
+                let value = match (value, state.fields.get(&name)) {
+                    (Some(value), _) => value,
+                    (None, Some(field)) => match field.value {
+                        FieldValue::Text(_) => FieldValue::Text(String::new()),
+                        FieldValue::Bool(_) => FieldValue::Bool(false),
+                    },
+                    (None, None) => continue,
+                };
+                let field = state.fields.entry(name).or_default();
+                field.initial = value.clone();
+                field.value = value;
+            }
+        }
+        (self.update)();
+        Ok(())
+    }
+
+    /// Collects the current values into a struct or map, matching fields by
+    /// `name`.
+    ///
+    /// Text is parsed into the type of the target field, e.g. numbers or
+    /// enums, and empty text is `None` for `Option`s. If a value cannot be
+    /// converted, the field is marked invalid with a custom error and the
+    /// message is shown until the field is changed.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, FormError> {
+        self.clear_errors();
+        let values = self
+            .values()
+            .into_iter()
+            .map(|(name, value)| (name.clone(), FieldDeserializer { name, value }));
+        let result = T::deserialize(MapDeserializer::new(values));
+        if let Err(FormError {
+            field: Some(name),
+            message,
+        }) = &result
+        {
+            self.set_error(name, message);
+        }
+        result
+    }
+
+    /// The message of the conversion error of the field `name` from the last
+    /// call of [`Form::deserialize`], until the field is changed.
+    pub fn error(&self, name: &str) -> Option<String> {
+        self.state
+            .borrow()
+            .fields
+            .get(name)
+            .and_then(|field| field.error.clone())
+    }
+
+    /// Clears the conversion errors of all fields.
+    fn clear_errors(&self) {
+        let ids: Vec<String> = {
+            let mut state = self.state.borrow_mut();
+            state
+                .fields
+                .values_mut()
+                .filter_map(|field| {
+                    field.error.take()?;
+                    field.validity = None;
+                    Some(field.ids.clone())
+                })
+                .flatten()
+                .collect()
+        };
+        for elem in ids.iter().filter_map(|id| crate::get_elem_by_id(id)) {
+            super::set_custom_validity(&elem, "");
+        }
+    }
+
+    /// Marks the field `name` as invalid and shows `message` on its elements.
+    fn set_error(&self, name: &str, message: &str) {
+        let ids = {
+            let mut state = self.state.borrow_mut();
+            let Some(field) = state.fields.get_mut(name) else {
+                return;
+            };
+            let mut validity = ValidityState::new();
+            validity.set_custom_error(true).set_valid(false);
+            field.validity = Some(validity);
+            field.error = Some(message.to_owned());
+            field.ids.clone()
+        };
+        for elem in ids.iter().filter_map(|id| crate::get_elem_by_id(id)) {
+            super::set_custom_validity(&elem, message);
+            super::report_validity(&elem);
+        }
+        (self.update)();
+    }
+}
+
+/// Deserializes a single [`FieldValue`], attributing errors to the field.
+struct FieldDeserializer {
+    name: String,
+    value: FieldValue,
+}
+
+impl<'de> IntoDeserializer<'de, FormError> for FieldDeserializer {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FormError> {
+                let result = match &self.value {
+                    FieldValue::Text(text) => match text.trim().parse() {
+                        Ok(value) => visitor.$visit(value),
+                        Err(_) => Err(de::Error::invalid_value(Unexpected::Str(text), &visitor)),
+                    },
+                    FieldValue::Bool(value) => {
+                        Err(de::Error::invalid_type(Unexpected::Bool(*value), &visitor))
+                    }
+                };
+                result.map_err(|err: FormError| err.in_field(&self.name))
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for FieldDeserializer {
+    type Error = FormError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FormError> {
+        match self.value {
+            FieldValue::Text(text) => visitor.visit_string(text),
+            FieldValue::Bool(value) => visitor.visit_bool(value),
+        }
+        .map_err(|err: FormError| err.in_field(&self.name))
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FormError> {
+        match &self.value {
+            FieldValue::Bool(value) => visitor.visit_bool(*value),
+            FieldValue::Text(text) => match text.trim().parse() {
+                Ok(value) => visitor.visit_bool(value),
+                Err(_) => Err(de::Error::invalid_value(Unexpected::Str(text), &visitor)),
+            },
+        }
+        .map_err(|err: FormError| err.in_field(&self.name))
+    }
+
+    deserialize_parsed! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FormError> {
+        match &self.value {
+            FieldValue::Text(text) if text.is_empty() => visitor.visit_none(),
+            _ => {
+                let name = self.name.clone();
+                visitor
+                    .visit_some(self)
+                    .map_err(|err: FormError| err.in_field(&name))
+            }
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, FormError> {
+        match &self.value {
+            FieldValue::Text(text) => {
+                let variant: StrDeserializer<FormError> = text.as_str().into_deserializer();
+                visitor.visit_enum(variant)
+            }
+            FieldValue::Bool(value) => {
+                Err(de::Error::invalid_type(Unexpected::Bool(*value), &visitor))
+            }
+        }
+        .map_err(|err: FormError| err.in_field(&self.name))
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, FormError> {
+        let name = self.name.clone();
+        visitor
+            .visit_newtype_struct(self)
+            .map_err(|err: FormError| err.in_field(&name))
+    }
+
+    forward_to_deserialize_any! {
+        str string bytes byte_buf unit unit_struct seq tuple tuple_struct map
+        struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::form::FieldState;
+
+    fn form(fields: &[(&str, FieldValue)]) -> Form {
+        let form = Form {
+            state: Rc::new(RefCell::default()),
+            update: Arc::new(|| {}),
+        };
+        for (name, value) in fields {
+            form.state.borrow_mut().fields.insert(
+                name.to_string(),
+                FieldState {
+                    value: value.clone(),
+                    initial: value.clone(),
+                    ..Default::default()
+                },
+            );
+        }
+        form
+    }
+
+    fn text(text: &str) -> FieldValue {
+        FieldValue::Text(text.to_owned())
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Plan {
+        Free,
+        Pro,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Signup {
+        name: String,
+        age: u8,
+        height: f64,
+        plan: Plan,
+        newsletter: bool,
+        nickname: Option<String>,
+        referrer: Option<u32>,
+    }
+
+    #[test]
+    fn deserialize_converts_values() {
+        let form = form(&[
+            ("name", text("Ada")),
+            ("age", text(" 36 ")),
+            ("height", text("1.65")),
+            ("plan", text("pro")),
+            ("newsletter", FieldValue::Bool(true)),
+            ("nickname", text("")),
+            ("referrer", text("7")),
+        ]);
+        assert_eq!(
+            form.deserialize::<Signup>(),
+            Ok(Signup {
+                name: "Ada".to_owned(),
+                age: 36,
+                height: 1.65,
+                plan: Plan::Pro,
+                newsletter: true,
+                nickname: None,
+                referrer: Some(7),
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_parses_bool_text() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Flag {
+            flag: bool,
+        }
+
+        let form = form(&[("flag", text("false"))]);
+        assert_eq!(form.deserialize::<Flag>(), Ok(Flag { flag: false }));
+    }
+
+    #[test]
+    fn deserialize_reports_missing_field() {
+        let form = form(&[("name", text("Ada"))]);
+        let err = form.deserialize::<Signup>().unwrap_err();
+        assert_eq!(err.field(), Some("age"));
+        assert_eq!(err.message(), "missing field");
+    }
+
+    #[test]
+    fn deserialize_attributes_errors_to_field() {
+        let form = form(&[
+            ("name", text("Ada")),
+            ("age", text("old")),
+            ("height", text("1.65")),
+            ("plan", text("pro")),
+            ("newsletter", FieldValue::Bool(false)),
+        ]);
+        let err = form.deserialize::<Signup>().unwrap_err();
+        assert_eq!(err.field(), Some("age"));
+        assert!(err.to_string().starts_with("field `age`: "));
+        assert_eq!(form.error("age").as_deref(), Some(err.message()));
+        assert!(!form.state.borrow().fields["age"].validity.unwrap().valid());
+    }
+
+    #[test]
+    fn deserialize_attributes_errors_inside_options_and_enums() {
+        let form = form(&[
+            ("name", text("Ada")),
+            ("age", text("36")),
+            ("height", text("1.65")),
+            ("plan", text("enterprise")),
+            ("newsletter", FieldValue::Bool(false)),
+            ("referrer", text("-1")),
+        ]);
+        assert_eq!(
+            form.deserialize::<Signup>().unwrap_err().field(),
+            Some("plan")
+        );
+
+        form.state
+            .borrow_mut()
+            .fields
+            .get_mut("plan")
+            .unwrap()
+            .value = text("free");
+        assert_eq!(
+            form.deserialize::<Signup>().unwrap_err().field(),
+            Some("referrer")
+        );
+        assert_eq!(form.error("plan"), None);
+    }
+
+    #[test]
+    fn deserialize_rejects_bool_for_number() {
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Count {
+            count: u32,
+        }
+
+        let form = form(&[("count", FieldValue::Bool(true))]);
+        let err = form.deserialize::<Count>().unwrap_err();
+        assert_eq!(err.field(), Some("count"));
+    }
+
+    #[test]
+    fn fill_converts_values() {
+        #[derive(Serialize)]
+        struct Values {
+            name: &'static str,
+            age: u8,
+            newsletter: bool,
+        }
+
+        let form = form(&[]);
+        form.fill(&Values {
+            name: "Ada",
+            age: 36,
+            newsletter: true,
+        })
+        .unwrap();
+        assert_eq!(form.value("name"), Some(text("Ada")));
+        assert_eq!(form.value("age"), Some(text("36")));
+        assert_eq!(form.value("newsletter"), Some(FieldValue::Bool(true)));
+        assert!(!form.is_dirty());
+    }
+
+    #[test]
+    fn fill_clears_by_field_kind() {
+        #[derive(Serialize)]
+        struct Values {
+            nickname: Option<String>,
+            newsletter: Option<bool>,
+            unknown: Option<String>,
+        }
+
+        let form = form(&[
+            ("nickname", text("Ada")),
+            ("newsletter", FieldValue::Bool(true)),
+        ]);
+        form.fill(&Values {
+            nickname: None,
+            newsletter: None,
+            unknown: None,
+        })
+        .unwrap();
+        assert_eq!(form.value("nickname"), Some(text("")));
+        assert_eq!(form.value("newsletter"), Some(FieldValue::Bool(false)));
+        assert_eq!(form.value("unknown"), None);
+    }
+
+    #[test]
+    fn fill_rejects_nested_values() {
+        let form = form(&[]);
+        let err = form
+            .fill(&serde_json::json!({ "tags": ["a", "b"] }))
+            .unwrap_err();
+        assert_eq!(err.field(), Some("tags"));
+
+        let err = form.fill("text").unwrap_err();
+        assert_eq!(err.field(), None);
+    }
+}
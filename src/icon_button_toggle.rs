@@ -4,12 +4,12 @@ mod on_icon;
 pub use off_icon::*;
 pub use on_icon::*;
 
-use crate::bool_to_option;
+use crate::{event_details_into, StaticCallback};
+use dioxus::prelude::*;
 use gloo::events::EventListener;
+use js_sys::Object;
 use wasm_bindgen::prelude::*;
 use web_sys::Node;
-use yew::prelude::*;
-use yew::virtual_dom::AttrValue;
 
 #[wasm_bindgen(module = "/build/mwc-icon-button-toggle.js")]
 extern "C" {
@@ -20,92 +20,113 @@ extern "C" {
     #[wasm_bindgen(getter, static_method_of = IconButtonToggle)]
     fn _dummy_loader() -> JsValue;
 
-    #[wasm_bindgen(method, getter)]
-    fn on(this: &IconButtonToggle) -> bool;
+    #[wasm_bindgen(method, setter)]
+    fn set_on(this: &IconButtonToggle, value: bool);
 }
 
 loader_hack!(IconButtonToggle);
 
-/// The `mwc-icon-button-toggle` component
-///
-/// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/icon-button-toggle)
-pub struct MatIconButtonToggle {
-    node_ref: NodeRef,
-    change_listener: Option<EventListener>,
-}
-
 /// Props for [`MatIconButtonToggle`]
 ///
 /// MWC Documentation:
 ///
 /// - [Properties](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/icon-button-toggle#propertiesattributes)
 /// - [Events](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/icon-button-toggle#events)
-#[derive(Debug, Properties, PartialEq, Clone)]
-pub struct IconButtonToggleProps {
-    #[prop_or_default]
-    pub on: bool,
-    #[prop_or_default]
-    pub on_icon: Option<AttrValue>,
-    #[prop_or_default]
-    pub off_icon: Option<AttrValue>,
-    #[prop_or_default]
-    pub label: Option<AttrValue>,
-    #[prop_or_default]
+#[derive(Props)]
+pub struct IconButtonToggleProps<'a> {
+    /// Binds to the `on` property. This is applied on every render, so
+    /// `_onchange` should be used to keep it in sync when the user clicks the
+    /// button.
+    // props starting with `on` are treated as event handlers by dioxus, hence the different
+    // names for this and the icon props
+    #[props(default)]
+    pub is_on: bool,
+    /// Binds to the `onIcon` property
+    #[props(into)]
+    pub icon_on: Option<String>,
+    /// Binds to the `offIcon` property
+    #[props(into)]
+    pub icon_off: Option<String>,
+    #[props(into)]
+    pub label: Option<String>,
+    #[props(default)]
     pub disabled: bool,
-    /// Binds to `MDCIconButtonToggle:change`.
+    /// Binds to `icon-button-toggle-change`.
     ///
     /// Callback's parameter is the `isOn` value passed
     ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub onchange: Callback<bool>,
-    #[prop_or_default]
-    pub children: Children,
-}
+    #[props(into)]
+    // the name cannot start with `on` or dioxus will expect an `EventHandler` which aren't static
+    // and thus cannot be used here
+    pub _onchange: Option<StaticCallback<bool>>,
+    /// Use [`MatOnIconButtonToggle`] and [`MatOffIconButtonToggle`] to slot
+    /// custom icons
+    #[props(default)]
+    pub children: Element<'a>,
 
-impl Component for MatIconButtonToggle {
-    type Message = ();
-    type Properties = IconButtonToggleProps;
+    #[props(into, default)]
+    pub style: String,
+    #[props(into, default)]
+    pub class: String,
+    #[props(into)]
+    pub slot: Option<String>,
+    #[props(default)]
+    pub dialog_initial_focus: bool,
+}
 
-    fn create(_: &Context<Self>) -> Self {
-        IconButtonToggle::ensure_loaded();
-        Self {
-            node_ref: NodeRef::default(),
-            change_listener: None,
+fn render<'a>(cx: Scope<'a, IconButtonToggleProps<'a>>) -> Element<'a> {
+    let id = crate::use_id(cx, "icon-button-toggle");
+    let change_listener = cx.use_hook(|| None);
+    if let Some(elem) = crate::get_elem_by_id(id) {
+        let target = elem.clone();
+        let toggle = JsValue::from(elem).dyn_into::<IconButtonToggle>().unwrap();
+        toggle.set_on(cx.props.is_on);
+        if let Some(listener) = cx.props._onchange.clone() {
+            *change_listener = Some(EventListener::new(
+                &target,
+                "icon-button-toggle-change",
+                move |event| listener.call(event_details_into::<ChangeDetailJS>(event).is_on()),
+            ));
         }
     }
 
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props();
-        html! {
-             <mwc-icon-button-toggle
-                 on={bool_to_option(props.on)}
-                 onIcon={props.on_icon.clone()}
-                 offIcon={props.off_icon.clone()}
-                 label={props.label.clone()}
-                 disabled={props.disabled}
-                 ref={self.node_ref.clone()}
-             > {props.children.clone()}</mwc-icon-button-toggle>
-        }
-    }
+    render! {
+        mwc-icon-button-toggle {
+            id: id,
 
-    fn changed(&mut self, _ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
-        // clear event listener in case the props changed
-        self.change_listener = None;
-        true
-    }
+            // quoted, because dioxus treats unquoted names starting with `on` as event handlers
+            "on": bool_attr!(cx.props.is_on),
+            "onIcon": optional_string_attr!(cx.props.icon_on),
+            offIcon: optional_string_attr!(cx.props.icon_off),
+            label: optional_string_attr!(cx.props.label),
+            disabled: bool_attr!(cx.props.disabled),
 
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        let props = ctx.props();
-        if self.change_listener.is_none() {
-            let element = self.node_ref.cast::<IconButtonToggle>().unwrap();
+            style: string_attr!(cx.props.style),
+            class: string_attr!(cx.props.class),
+            slot: optional_string_attr!(cx.props.slot),
+            dialogInitialFocus: bool_attr!(cx.props.dialog_initial_focus),
 
-            let callback = props.onchange.clone();
-            self.change_listener = Some(EventListener::new(
-                &element.clone(),
-                "MDCIconButtonToggle:change",
-                move |_| callback.emit(element.on()),
-            ));
+            &cx.props.children
         }
     }
 }
+
+component!(
+    'a,
+    MatIconButtonToggle,
+    IconButtonToggleProps,
+    render,
+    IconButtonToggle,
+    "icon-button-toggle"
+);
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug)]
+    #[wasm_bindgen(extends = Object)]
+    type ChangeDetailJS;
+
+    #[wasm_bindgen(method, getter, js_name = isOn)]
+    fn is_on(this: &ChangeDetailJS) -> bool;
+}
@@ -1,50 +1,23 @@
-use yew::prelude::*;
+use dioxus::prelude::*;
 
 const SLOT: &str = "offIcon";
 
 /// Props for [`MatOffIconButtonToggle`]
-#[derive(Properties, PartialEq, Clone)]
-pub struct OffIconButtonToggleProps {
-    pub children: Children,
+#[derive(Props)]
+pub struct OffIconButtonToggleProps<'a> {
+    pub children: Element<'a>,
 }
 
-/// Defines header for [`MatIconButtonToggle`][crate::MatIconButtonToggle].
+/// Defines the off icon for [`MatIconButtonToggle`][crate::MatIconButtonToggle].
 ///
-/// If the child passed is an element (a `VTag`), then it is modified to include
-/// the appropriate attributes. Otherwise, the child is wrapped in a `span`
-/// containing said attributes.
-pub struct MatOffIconButtonToggle {}
-
-impl Component for MatOffIconButtonToggle {
-    type Message = ();
-    type Properties = OffIconButtonToggleProps;
-
-    fn create(_: &Context<Self>) -> Self {
-        Self {}
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props();
-        let children = props
-            .children
-            .iter()
-            .map(|child| match child {
-                Html::VTag(mut vtag) => {
-                    vtag.add_attribute("slot", SLOT);
-                    Html::VTag(vtag)
-                }
-                _ => {
-                    html! {
-                         <span slot={SLOT}>
-                             {child}
-                         </span>
-                    }
-                }
-            })
-            .collect::<Html>();
-
-        html! {
-             {children}
+/// The passed children, e.g. an `svg` or a [`MatIcon`](crate::MatIcon), are
+/// wrapped in a `span` with the required attributes set.
+#[allow(non_snake_case)]
+pub fn MatOffIconButtonToggle<'a>(cx: Scope<'a, OffIconButtonToggleProps<'a>>) -> Element<'a> {
+    render! {
+        span {
+            slot: SLOT,
+            &cx.props.children
         }
     }
 }
@@ -1,50 +1,23 @@
-use yew::prelude::*;
+use dioxus::prelude::*;
 
 const SLOT: &str = "onIcon";
 
 /// Props for [`MatOnIconButtonToggle`]
-#[derive(Properties, PartialEq, Clone)]
-pub struct OnIconButtonToggleProps {
-    pub children: Children,
+#[derive(Props)]
+pub struct OnIconButtonToggleProps<'a> {
+    pub children: Element<'a>,
 }
 
-/// Defines header for [`MatIconButtonToggle`][crate::MatIconButtonToggle].
+/// Defines the on icon for [`MatIconButtonToggle`][crate::MatIconButtonToggle].
 ///
-/// If the child passed is an element (a `VTag`), then it is modified to include
-/// the appropriate attributes. Otherwise, the child is wrapped in a `span`
-/// containing said attributes.
-pub struct MatOnIconButtonToggle {}
-
-impl Component for MatOnIconButtonToggle {
-    type Message = ();
-    type Properties = OnIconButtonToggleProps;
-
-    fn create(_: &Context<Self>) -> Self {
-        Self {}
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props();
-        let children = props
-            .children
-            .iter()
-            .map(|child| match child {
-                Html::VTag(mut vtag) => {
-                    vtag.add_attribute("slot", SLOT);
-                    Html::VTag(vtag)
-                }
-                _ => {
-                    html! {
-                         <span slot={SLOT}>
-                             {child}
-                         </span>
-                    }
-                }
-            })
-            .collect::<Html>();
-
-        html! {
-             {children}
+/// The passed children, e.g. an `svg` or a [`MatIcon`](crate::MatIcon), are
+/// wrapped in a `span` with the required attributes set.
+#[allow(non_snake_case)]
+pub fn MatOnIconButtonToggle<'a>(cx: Scope<'a, OnIconButtonToggleProps<'a>>) -> Element<'a> {
+    render! {
+        span {
+            slot: SLOT,
+            &cx.props.children
         }
     }
 }
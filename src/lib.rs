@@ -228,8 +228,9 @@ pub use icon_button_toggle::MatIconButtonToggle;
 #[cfg(feature = "slider")]
 pub mod slider;
 #[cfg(feature = "slider")]
+#[doc(no_inline)]
 #[doc(hidden)]
-pub use slider::MatSlider;
+pub use slider::{MatRangeSlider, MatSlider};
 
 #[cfg(feature = "tabs")]
 pub mod tabs;
@@ -244,7 +245,12 @@ pub mod snackbar;
 #[doc(hidden)]
 pub use snackbar::MatSnackbar;
 
-#[cfg(any(feature = "textfield", feature = "textarea"))]
+#[cfg(any(
+    feature = "textfield",
+    feature = "textarea",
+    feature = "select",
+    feature = "form"
+))]
 pub mod text_inputs;
 #[cfg(feature = "textarea")]
 #[doc(no_inline)]
@@ -253,7 +259,7 @@ pub use text_inputs::MatTextArea;
 #[cfg(feature = "textfield")]
 #[doc(no_inline)]
 #[doc(hidden)]
-pub use text_inputs::MatTextField;
+pub use text_inputs::{MatDateField, MatNumberField, MatTextField, MatTimeField};
 
 #[cfg(feature = "select")]
 pub mod select;
@@ -267,16 +273,22 @@ pub mod menu;
 #[doc(hidden)]
 pub use menu::MatMenu;
 
+#[cfg(feature = "form")]
+pub mod form;
+#[cfg(feature = "form")]
+#[doc(hidden)]
+pub use form::{use_form, MatForm};
+
 #[cfg(feature = "theming")]
 pub mod theming;
 #[cfg(feature = "theming")]
 #[doc(hidden)]
-pub use theming::MatTheme;
+pub use theming::{MatTheme, MatThemeScope};
 
 #[cfg(feature = "palette")]
 pub mod palette;
 
-pub use utils::StaticCallback;
+pub use utils::{ElementRef, StaticCallback};
 
 #[wasm_bindgen(module = "/build/core.js")]
 extern "C" {
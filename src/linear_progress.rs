@@ -1,6 +1,5 @@
-use crate::{bool_to_option, to_option_string};
+use dioxus::{core::AttributeValue, prelude::*};
 use wasm_bindgen::prelude::*;
-use yew::prelude::*;
 
 #[wasm_bindgen(module = "/build/mwc-linear-progress.js")]
 extern "C" {
@@ -16,34 +15,48 @@ loader_hack!(LinearProgress);
 /// Props for [`MatLinearProgress`]
 ///
 /// [MWC Documentation for properties](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/linear-progress#propertiesattributes)
-#[derive(Debug, Properties, PartialEq, Clone)]
+#[derive(Props, PartialEq)]
 pub struct LinearProgressProps {
-    #[prop_or_default]
+    #[props(default)]
     pub indeterminate: bool,
-    #[prop_or_default]
+    #[props(default)]
     pub progress: f32,
-    #[prop_or_default]
+    /// Defaults to `1.0`, which hides the buffer
+    #[props(default = 1.0)]
     pub buffer: f32,
-    #[prop_or_default]
+    #[props(default)]
     pub reverse: bool,
-    #[prop_or_default]
+    #[props(default)]
     pub closed: bool,
+
+    #[props(into, default)]
+    pub style: String,
+    #[props(into, default)]
+    pub class: String,
+    #[props(into)]
+    pub slot: Option<String>,
+}
+
+fn render(cx: Scope<LinearProgressProps>) -> Element {
+    render! {
+        mwc-linear-progress {
+            indeterminate: bool_attr!(cx.props.indeterminate),
+            progress: AttributeValue::Float(cx.props.progress.into()),
+            buffer: AttributeValue::Float(cx.props.buffer.into()),
+            reverse: bool_attr!(cx.props.reverse),
+            closed: bool_attr!(cx.props.closed),
+
+            style: string_attr!(cx.props.style),
+            class: string_attr!(cx.props.class),
+            slot: optional_string_attr!(cx.props.slot),
+        }
+    }
 }
 
 component!(
     MatLinearProgress,
     LinearProgressProps,
-    |props: &LinearProgressProps| {
-        html! {
-             <mwc-linear-progress
-                 indeterminate={bool_to_option(props.indeterminate)}
-                 progress={to_option_string(props.progress)}
-                 buffer={to_option_string(props.buffer)}
-                 reverse={bool_to_option(props.reverse)}
-                 closed={bool_to_option(props.closed)}
-             ></mwc-linear-progress>
-        }
-    },
+    render,
     LinearProgress,
     "linear-progress"
 );
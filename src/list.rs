@@ -30,6 +30,7 @@ use gloo::events::EventListener;
 use wasm_bindgen::prelude::*;
 use web_sys::Node;
 
+use crate::utils::WeakComponentLink;
 use crate::{event_into_details, StaticCallback};
 
 #[wasm_bindgen(module = "/build/mwc-list.js")]
@@ -44,11 +45,17 @@ extern "C" {
     #[wasm_bindgen(method, getter)]
     fn index(this: &List) -> JsValue;
 
+    #[wasm_bindgen(method, getter)]
+    fn selected(this: &List) -> JsValue;
+
+    #[wasm_bindgen(method)]
+    fn select(this: &List, index: &JsValue);
+
     #[wasm_bindgen(method)]
-    fn toggle(this: &List, index: usize, force: bool);
+    fn toggle(this: &List, index: usize, force: Option<bool>);
 
     #[wasm_bindgen(method, js_name = getFocusedItemIndex)]
-    fn get_focused_item_index(this: &List) -> usize;
+    fn get_focused_item_index(this: &List) -> i32;
 
     #[wasm_bindgen(method, js_name = focusItemAtIndex)]
     fn focus_item_at_index(this: &List, index: usize);
@@ -86,15 +93,9 @@ pub struct ListProps<'a> {
     /// Binds to `selected` event `mwc-list`
     #[props(into)]
     pub _onselected: Option<StaticCallback<SelectedDetail>>,
-    // TODO: make methods callable
-    // /// [`WeakComponentLink`] for `MatList` which provides the following methods
-    // /// - ```toggle(&self, index: usize, force: bool)```
-    // /// - ```get_focused_item_index(&self) -> usize```
-    // /// - ```focus_item_at_index(&self, index: usize)```
-    // ///
-    // /// See [`WeakComponentLink`] documentation for more information
-    // #[props(default)]
-    // pub list_link: WeakComponentLink<MatList>,
+    /// [`ListLink`] for calling methods of this list
+    #[props(default)]
+    pub list_link: ListLink,
     pub children: Element<'a>,
 
     #[props(into, default)]
@@ -109,6 +110,7 @@ fn render<'a>(cx: Scope<'a, ListProps<'a>>) -> Element<'a> {
     let id = crate::use_id(cx, "list");
     let selected_listener = cx.use_hook(|| None);
     let action_listener = cx.use_hook(|| None);
+    cx.props.list_link.0.set_id(id);
     if let Some(elem) = crate::get_elem_by_id(id) {
         let target = elem.clone();
         let list = JsValue::from(elem).dyn_into::<List>().unwrap();
@@ -149,3 +151,76 @@ fn render<'a>(cx: Scope<'a, ListProps<'a>>) -> Element<'a> {
 }
 
 component!('a, MatList, ListProps, render, List, "list");
+
+/// Link to a rendered [`MatList`], passed to it via [`ListProps::list_link`].
+///
+/// The link should be created once per component, e.g. with
+/// `cx.use_hook(ListLink::default)`. Calling methods while the list is not
+/// mounted has no effect.
+#[derive(Clone, Default, PartialEq)]
+pub struct ListLink(WeakComponentLink);
+
+impl ListLink {
+    /// Binds to `toggle` method.
+    ///
+    /// Toggles the selection of the item at `index`. If `force` is set, the
+    /// item is selected for `Some(true)` and deselected for `Some(false)`.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/list#methods) for details
+    pub fn toggle(&self, index: usize, force: Option<bool>) {
+        if let Some(list) = self.0.cast::<List>() {
+            list.toggle(index, force);
+        }
+    }
+
+    /// Binds to `select` method.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/list#methods) for details
+    pub fn select(&self, index: &ListIndex) {
+        if let Some(list) = self.0.cast::<List>() {
+            list.select(&index.into());
+        }
+    }
+
+    /// Binds to `getFocusedItemIndex` method.
+    ///
+    /// Returns `None` if no item is focused or the list is not mounted.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/list#methods) for details
+    pub fn get_focused_item_index(&self) -> Option<usize> {
+        self.0
+            .cast::<List>()
+            .and_then(|list| usize::try_from(list.get_focused_item_index()).ok())
+    }
+
+    /// Binds to `focusItemAtIndex` method.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/list#methods) for details
+    pub fn focus_item_at_index(&self, index: usize) {
+        if let Some(list) = self.0.cast::<List>() {
+            list.focus_item_at_index(index);
+        }
+    }
+
+    /// Getter for `index`, `None` if the list is not mounted.
+    pub fn index(&self) -> Option<ListIndex> {
+        self.0
+            .cast::<List>()
+            .map(|list| ListIndex::from(list.index()))
+    }
+
+    /// Getter for `selected`, the elements of all selected items. `None` if
+    /// the list is not mounted.
+    pub fn selected(&self) -> Option<Vec<web_sys::Element>> {
+        self.0.cast::<List>().map(|list| {
+            let selected = list.selected();
+            if selected.is_null() || selected.is_undefined() {
+                vec![]
+            } else if let Some(items) = selected.dyn_ref::<js_sys::Array>() {
+                items.iter().map(JsCast::unchecked_into).collect()
+            } else {
+                vec![selected.unchecked_into()]
+            }
+        })
+    }
+}
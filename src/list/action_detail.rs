@@ -6,10 +6,9 @@ use wasm_bindgen::JsCast;
 /// The `ActionDetail` type
 ///
 /// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/list#mwc-list-2)
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ActionDetail {
-    #[allow(dead_code)]
-    index: ListIndex,
+    pub index: ListIndex,
 }
 
 impl From<JsValue> for ActionDetail {
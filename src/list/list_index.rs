@@ -52,3 +52,18 @@ impl From<JsValue> for ListIndex {
         }
     }
 }
+
+impl From<&ListIndex> for JsValue {
+    fn from(index: &ListIndex) -> Self {
+        match index {
+            ListIndex::Single(val) => JsValue::from(val.map_or(-1.0, |val| val as f64)),
+            ListIndex::Multi(val) => {
+                let set = js_sys::Set::new(&JsValue::UNDEFINED);
+                for index in val {
+                    set.add(&JsValue::from(*index as f64));
+                }
+                set.into()
+            }
+        }
+    }
+}
@@ -3,12 +3,13 @@ mod models;
 pub use models::*;
 
 use crate::list::{ListIndex, SelectedDetail};
-use crate::{bool_to_option, event_into_details, to_option_string, WeakComponentLink};
+use crate::utils::WeakComponentLink;
+use crate::{event_into_details, StaticCallback};
+use dioxus::core::AttributeValue;
+use dioxus::prelude::*;
 use gloo::events::EventListener;
 use wasm_bindgen::prelude::*;
 use web_sys::Node;
-use yew::prelude::*;
-use yew::virtual_dom::AttrValue;
 
 #[wasm_bindgen(module = "/build/mwc-menu.js")]
 extern "C" {
@@ -28,283 +29,225 @@ extern "C" {
     #[wasm_bindgen(method)]
     fn close(this: &Menu);
 
-    // `MWCMenuIndex` is completely undocumented
     #[wasm_bindgen(method)]
-    fn select(this: &Menu, index: &JsValue) -> usize;
+    fn select(this: &Menu, index: &JsValue);
 
     #[wasm_bindgen(method, js_name = getFocusedItemIndex)]
-    fn get_focused_item_index(this: &Menu) -> usize;
+    fn get_focused_item_index(this: &Menu) -> i32;
 
     #[wasm_bindgen(method, js_name = focusItemAtIndex)]
     fn focus_item_at_index(this: &Menu, index: usize);
 
     #[wasm_bindgen(method, setter)]
-    fn set_anchor(this: &Menu, value: &web_sys::HtmlElement);
+    fn set_anchor(this: &Menu, value: &web_sys::Element);
 }
 
 loader_hack!(Menu);
 
-/// The `mwc-menu` Component
-///
-/// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/menu)
-pub struct MatMenu {
-    node_ref: NodeRef,
-    opened_listener: Option<EventListener>,
-    closed_listener: Option<EventListener>,
-    action_listener: Option<EventListener>,
-    selected_listener: Option<EventListener>,
-}
-
-/// Props for `MatMenu`
+/// Props for [`MatMenu`]
 ///
 /// MWC Documentation [properties](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/menu#propertiesattributes)
 /// and [events](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/menu#events)
-#[derive(Properties, PartialEq, Clone)]
-pub struct MenuProps {
-    /// Changing this prop re-renders the component.
-    /// For general usage, consider using `show` method provided by
-    /// `WeakComponentLink<MatMenu>` via `menu_link`
-    #[prop_or_default]
+#[derive(Props)]
+pub struct MenuProps<'a> {
+    #[props(default)]
     pub open: bool,
-    #[prop_or_default]
-    pub anchor: Option<web_sys::HtmlElement>,
-    #[prop_or(Corner::TopStart)]
+    /// The element the menu is positioned relative to. Either the `id` of a
+    /// rendered element or an element obtained with
+    /// [`MenuAnchor::from_mounted`].
+    #[props(into)]
+    pub anchor: Option<MenuAnchor>,
+    #[props(default = Corner::TopStart)]
     pub corner: Corner,
-    #[prop_or(MenuCorner::Start)]
+    #[props(default = MenuCorner::Start)]
     pub menu_corner: MenuCorner,
-    #[prop_or_default]
+    #[props(default)]
     pub quick: bool,
-    #[prop_or_default]
+    #[props(default)]
     pub absolute: bool,
-    #[prop_or_default]
+    #[props(default)]
     pub fixed: bool,
-    #[prop_or_default]
-    pub x: Option<isize>,
-    #[prop_or_default]
-    pub y: Option<isize>,
-    #[prop_or_default]
+    #[props(default)]
+    pub x: Option<i64>,
+    #[props(default)]
+    pub y: Option<i64>,
+    #[props(default)]
     pub force_group_selection: bool,
-    #[prop_or(DefaultFocusState::ListRoot)]
+    #[props(default = DefaultFocusState::ListRoot)]
     pub default_focus: DefaultFocusState,
-    #[prop_or_default]
+    #[props(default)]
     pub fullwidth: bool,
-    #[prop_or_default]
+    #[props(default)]
     pub wrap_focus: bool,
-    #[prop_or_default]
-    pub inner_role: Option<AttrValue>,
-    #[prop_or_default]
+    #[props(into)]
+    pub inner_role: Option<String>,
+    #[props(default)]
     pub multi: bool,
-    #[prop_or_default]
+    #[props(default)]
     pub activatable: bool,
     /// Binds to `opened` event on `mwc-menu-surface`
     ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub onopened: Callback<()>,
+    #[props(into)]
+    // the name cannot start with `on` or dioxus will expect an `EventHandler` which aren't static
+    // and thus cannot be used here
+    pub _onopened: Option<StaticCallback<()>>,
     /// Binds to `closed` event on `mwc-menu-surface`
     ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub onclosed: Callback<()>,
+    #[props(into)]
+    pub _onclosed: Option<StaticCallback<()>>,
     /// Binds to `action` event on `mwc-list`
     ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub onaction: Callback<ListIndex>,
+    #[props(into)]
+    pub _onaction: Option<StaticCallback<ListIndex>>,
     /// Binds to `selected` event on `mwc-list`
     ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub onselected: Callback<SelectedDetail>,
-    /// `WeakComponentLink` for `MatMenu` which provides the following methods
-    /// - `get_focused_item_index(&self) -> usize`
-    /// - `focus_item_at_index(&self, index: usize)`
-    /// - `select(&self, index: &JsValue)`
-    /// - `show(&self)`
-    /// - `close(&self)`
-    ///
-    /// See [`WeakComponentLink`](/material_yew/struct.WeakComponentLink.html)
-    /// documentation for more information
-    #[prop_or_default]
-    pub menu_link: WeakComponentLink<MatMenu>,
-    pub children: Children,
+    #[props(into)]
+    pub _onselected: Option<StaticCallback<SelectedDetail>>,
+    /// [`MenuLink`] for calling methods of this menu
+    #[props(default)]
+    pub menu_link: MenuLink,
+    pub children: Element<'a>,
+
+    #[props(into, default)]
+    pub style: String,
+    #[props(into, default)]
+    pub class: String,
+    #[props(into)]
+    pub slot: Option<String>,
 }
 
-impl Component for MatMenu {
-    type Message = ();
-    type Properties = MenuProps;
-
-    fn create(ctx: &Context<Self>) -> Self {
-        ctx.props()
-            .menu_link
-            .borrow_mut()
-            .replace(ctx.link().clone());
-        Menu::ensure_loaded();
-        Self {
-            node_ref: NodeRef::default(),
-            opened_listener: None,
-            closed_listener: None,
-            action_listener: None,
-            selected_listener: None,
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props();
-        html! {
-             <mwc-menu
-                 open={props.open}
-                 corner={to_option_string(props.corner.to_string())}
-                 menuCorner={to_option_string(props.menu_corner.to_string())}
-                 quick={bool_to_option(props.quick)}
-                 absolute={bool_to_option(props.absolute)}
-                 fixed={bool_to_option(props.fixed)}
-                 x={props.x.map(|it| it.to_string())}
-                 y={props.y.map(|it| it.to_string())}
-                 forceGroupSelection={bool_to_option(props.force_group_selection)}
-                 defaultFocus={to_option_string(props.default_focus.to_string())}
-                 fullwidth={bool_to_option(props.fullwidth)}
-                 wrapFocus={bool_to_option(props.wrap_focus)}
-                 innerRole={props.inner_role.clone()}
-                 multi={bool_to_option(props.multi)}
-                 activatable={bool_to_option(props.activatable)}
-                 ref={self.node_ref.clone()}
-             >
-               {props.children.clone()}
-             </mwc-menu>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
-        let props = ctx.props();
-        let menu = self.node_ref.cast::<Menu>().unwrap();
-        if first_render {
-            if let Some(anchor) = props.anchor.as_ref() {
-                menu.set_anchor(anchor);
-            }
+fn render<'a>(cx: Scope<'a, MenuProps<'a>>) -> Element<'a> {
+    let id = crate::use_id(cx, "menu");
+    let opened_listener = cx.use_hook(|| None);
+    let closed_listener = cx.use_hook(|| None);
+    let action_listener = cx.use_hook(|| None);
+    let selected_listener = cx.use_hook(|| None);
+    cx.props.menu_link.0.set_id(id);
+    if let Some(elem) = crate::get_elem_by_id(id) {
+        let target = elem.clone();
+        let menu = JsValue::from(elem).dyn_into::<Menu>().unwrap();
+        if let Some(anchor) = cx.props.anchor.as_ref().and_then(MenuAnchor::element) {
+            menu.set_anchor(&anchor);
         }
-        if self.opened_listener.is_none() {
-            let onopened = props.onopened.clone();
-            self.opened_listener = Some(EventListener::new(&menu, "opened", move |_| {
-                onopened.emit(());
+        if let Some(listener) = cx.props._onopened.clone() {
+            *opened_listener = Some(EventListener::new(&target, "opened", move |_| {
+                listener.call(())
             }));
         }
-
-        if self.closed_listener.is_none() {
-            let onclosed = props.onclosed.clone();
-            self.closed_listener = Some(EventListener::new(&menu, "closed", move |_| {
-                onclosed.emit(());
+        if let Some(listener) = cx.props._onclosed.clone() {
+            *closed_listener = Some(EventListener::new(&target, "closed", move |_| {
+                listener.call(())
             }));
         }
-
-        if self.selected_listener.is_none() {
-            let onselected = props.onselected.clone();
-            self.selected_listener = Some(EventListener::new(&menu, "selected", move |event| {
-                onselected.emit(SelectedDetail::from(event_into_details(event)));
+        if let Some(listener) = cx.props._onselected.clone() {
+            *selected_listener = Some(EventListener::new(&target, "selected", move |event| {
+                listener.call(SelectedDetail::from(event_into_details(event)))
             }));
         }
-
-        if self.action_listener.is_none() {
-            let onaction = props.onaction.clone();
-            self.action_listener = Some(EventListener::new(&menu.clone(), "action", move |_| {
+        if let Some(listener) = cx.props._onaction.clone() {
+            *action_listener = Some(EventListener::new(&target, "action", move |_| {
                 let val: JsValue = menu.index();
-
                 let index = ListIndex::from(val);
-                onaction.emit(index);
+                listener.call(index)
             }));
         }
     }
+
+    render! {
+        mwc-menu {
+            id: id,
+
+            open: bool_attr!(cx.props.open),
+            corner: cx.props.corner.as_str(),
+            menuCorner: cx.props.menu_corner.as_str(),
+            quick: bool_attr!(cx.props.quick),
+            absolute: bool_attr!(cx.props.absolute),
+            fixed: bool_attr!(cx.props.fixed),
+            x: cx.props.x.map(|v| format_args!("{v}").into_value(cx.bump())).unwrap_or(AttributeValue::None),
+            y: cx.props.y.map(|v| format_args!("{v}").into_value(cx.bump())).unwrap_or(AttributeValue::None),
+            forceGroupSelection: bool_attr!(cx.props.force_group_selection),
+            defaultFocus: cx.props.default_focus.as_str(),
+            fullwidth: bool_attr!(cx.props.fullwidth),
+            wrapFocus: bool_attr!(cx.props.wrap_focus),
+            innerRole: optional_string_attr!(cx.props.inner_role),
+            multi: bool_attr!(cx.props.multi),
+            activatable: bool_attr!(cx.props.activatable),
+
+            style: string_attr!(cx.props.style),
+            class: string_attr!(cx.props.class),
+            slot: optional_string_attr!(cx.props.slot),
+
+            &cx.props.children
+        }
+    }
 }
 
-impl WeakComponentLink<MatMenu> {
-    /// Binds to `getFocusedItemIndex` method.
+component!('a, MatMenu, MenuProps, render, Menu, "menu");
+
+/// Link to a rendered [`MatMenu`], passed to it via [`MenuProps::menu_link`].
+///
+/// The link should be created once per component, e.g. with
+/// `cx.use_hook(MenuLink::default)`. Calling methods while the menu is not
+/// mounted has no effect.
+#[derive(Clone, Default, PartialEq)]
+pub struct MenuLink(WeakComponentLink);
+
+impl MenuLink {
+    /// Binds to `show` method.
     ///
     /// See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/menu#methods) for details
-    pub fn get_focused_item_index(&self) -> usize {
-        self.borrow()
-            .as_ref()
-            .unwrap()
-            .get_component()
-            .unwrap()
-            .node_ref
-            .cast::<Menu>()
-            .unwrap()
-            .get_focused_item_index()
+    pub fn show(&self) {
+        if let Some(menu) = self.0.cast::<Menu>() {
+            menu.show();
+        }
     }
 
-    /// Binds to `focusItemAtIndex` method.
+    /// Binds to `close` method.
     ///
     /// See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/menu#methods) for details
-    pub fn focus_item_at_index(&self, index: usize) {
-        self.borrow()
-            .as_ref()
-            .unwrap()
-            .get_component()
-            .unwrap()
-            .node_ref
-            .cast::<Menu>()
-            .unwrap()
-            .focus_item_at_index(index)
+    pub fn close(&self) {
+        if let Some(menu) = self.0.cast::<Menu>() {
+            menu.close();
+        }
     }
 
     /// Binds to `select` method.
     ///
-    /// `index` is `JsValue` because `MWCMenuIndex` mentioned in mwc docs is
-    /// completely undocumented.
-    ///
     /// See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/menu#methods) for details
-    pub fn select(&self, index: &JsValue) {
-        self.borrow()
-            .as_ref()
-            .unwrap()
-            .get_component()
-            .unwrap()
-            .node_ref
-            .cast::<Menu>()
-            .unwrap()
-            .select(index);
+    pub fn select(&self, index: &ListIndex) {
+        if let Some(menu) = self.0.cast::<Menu>() {
+            menu.select(&index.into());
+        }
     }
 
-    /// Binds to `show` method.
+    /// Binds to `getFocusedItemIndex` method.
+    ///
+    /// Returns `None` if no item is focused or the menu is not mounted.
     ///
     /// See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/menu#methods) for details
-    pub fn show(&self) {
-        self.borrow()
-            .as_ref()
-            .unwrap()
-            .get_component()
-            .unwrap()
-            .node_ref
+    pub fn get_focused_item_index(&self) -> Option<usize> {
+        self.0
             .cast::<Menu>()
-            .unwrap()
-            .show();
+            .and_then(|menu| usize::try_from(menu.get_focused_item_index()).ok())
     }
 
-    /// Binds to `close` method.
+    /// Binds to `focusItemAtIndex` method.
     ///
     /// See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/menu#methods) for details
-    pub fn close(&self) {
-        self.borrow()
-            .as_ref()
-            .unwrap()
-            .get_component()
-            .unwrap()
-            .node_ref
-            .cast::<Menu>()
-            .unwrap()
-            .close();
+    pub fn focus_item_at_index(&self, index: usize) {
+        if let Some(menu) = self.0.cast::<Menu>() {
+            menu.focus_item_at_index(index);
+        }
     }
 
-    /// Setter method for `anchor`.
-    pub fn set_anchor(&self, anchor: web_sys::HtmlElement) {
-        self.borrow()
-            .as_ref()
-            .unwrap()
-            .get_component()
-            .unwrap()
-            .node_ref
+    /// Getter for `index`, `None` if the menu is not mounted.
+    pub fn index(&self) -> Option<ListIndex> {
+        self.0
             .cast::<Menu>()
-            .unwrap()
-            .set_anchor(&anchor);
+            .map(|menu| ListIndex::from(menu.index()))
     }
 }
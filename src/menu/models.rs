@@ -1,7 +1,9 @@
+use std::fmt;
+
 /// The `Corner` type
 ///
 /// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/menu#propertiesattributes)
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum Corner {
     TopLeft,
     TopRight,
@@ -13,8 +15,8 @@ pub enum Corner {
     BottomEnd,
 }
 
-impl ToString for Corner {
-    fn to_string(&self) -> String {
+impl Corner {
+    pub fn as_str(&self) -> &'static str {
         use Corner::*;
         match self {
             TopLeft => "TOP_LEFT",
@@ -22,38 +24,48 @@ impl ToString for Corner {
             BottomLeft => "BOTTOM_LEFT",
             BottomRight => "BOTTOM_RIGHT",
             TopStart => "TOP_START",
-            TopEnd => "TOP_END ",
+            TopEnd => "TOP_END",
             BottomStart => "BOTTOM_START",
             BottomEnd => "BOTTOM_END",
         }
-        .to_string()
+    }
+}
+
+impl fmt::Display for Corner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
     }
 }
 
 /// The `MenuCorner` type
 ///
 /// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/menu#propertiesattributes)
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum MenuCorner {
     Start,
     End,
 }
 
-impl ToString for MenuCorner {
-    fn to_string(&self) -> String {
+impl MenuCorner {
+    pub fn as_str(&self) -> &'static str {
         use MenuCorner::*;
         match self {
             Start => "START",
             End => "END",
         }
-        .to_string()
+    }
+}
+
+impl fmt::Display for MenuCorner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
     }
 }
 
 /// The `DefaultFocusState` type
 ///
 /// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/menu#propertiesattributes)
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum DefaultFocusState {
     None,
     ListRoot,
@@ -61,8 +73,8 @@ pub enum DefaultFocusState {
     LastItem,
 }
 
-impl ToString for DefaultFocusState {
-    fn to_string(&self) -> String {
+impl DefaultFocusState {
+    pub fn as_str(&self) -> &'static str {
         use DefaultFocusState::*;
         match self {
             None => "NONE",
@@ -70,6 +82,16 @@ impl ToString for DefaultFocusState {
             FirstItem => "FIRST_ITEM",
             LastItem => "LAST_ITEM",
         }
-        .to_string()
     }
 }
+
+impl fmt::Display for DefaultFocusState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Type for [`MenuProps::anchor`][super::MenuProps::anchor].
+///
+/// Strings are converted to [`MenuAnchor::Id`].
+pub type MenuAnchor = crate::ElementRef;
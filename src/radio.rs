@@ -37,6 +37,11 @@ pub struct RadioProps<'a> {
     pub checked: bool,
     #[props(default)]
     pub disabled: bool,
+    /// Whether the radio, or any radio with the same `name`, must be checked
+    /// when a surrounding `MatForm` is submitted. Not validated by the radio
+    /// itself.
+    #[props(default)]
+    pub required: bool,
     #[props(into)]
     pub name: Option<String>,
     #[props(into)]
@@ -69,10 +74,33 @@ pub struct RadioProps<'a> {
 fn render<'a>(cx: Scope<'a, RadioProps<'a>>) -> Element<'a> {
     let id = crate::use_id(cx, "radio");
     let change_listener = cx.use_hook(|| None);
+    // all radios with the same name share one field holding the checked value
+    #[cfg(feature = "form")]
+    let form_field = crate::form::field::use_form_field(cx, id, cx.props.name.as_deref(), || {
+        cx.props
+            .checked
+            .then(|| cx.props.value.clone().unwrap_or_default().into())
+    });
     if let Some(elem) = crate::get_elem_by_id(id) {
         let target = elem.clone();
         let radio = JsValue::from(elem).dyn_into::<Radio>().unwrap();
-        radio.set_checked(cx.props.checked);
+        #[cfg(feature = "form")]
+        let form_value = form_field.map(|field| {
+            field.bind(
+                &target,
+                &["change"],
+                |elem| match crate::form::field::read_bool(elem, "checked") {
+                    Some(crate::form::FieldValue::Bool(true)) => {
+                        crate::form::field::read_text(elem)
+                    }
+                    _ => None,
+                },
+            );
+            field.value().as_text() == Some(cx.props.value.as_deref().unwrap_or_default())
+        });
+        #[cfg(not(feature = "form"))]
+        let form_value = None;
+        radio.set_checked(form_value.unwrap_or(cx.props.checked));
         if let Some(listener) = cx.props._onchange.clone() {
             *change_listener = Some(EventListener::new(&target, "change", move |_| {
                 listener.call(radio.checked())
@@ -84,6 +112,7 @@ fn render<'a>(cx: Scope<'a, RadioProps<'a>>) -> Element<'a> {
             id: id,
 
             disabled: bool_attr!(cx.props.disabled),
+            required: bool_attr!(cx.props.required),
             name: optional_string_attr!(cx.props.name),
             value: optional_string_attr!(cx.props.value),
             global: bool_attr!(cx.props.global),
@@ -1,16 +1,19 @@
 #[doc(inline)]
 pub use crate::list::{ActionDetail, ListIndex, SelectedDetail};
 
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
 use crate::text_inputs::{
-    validity_state::ValidityStateJS, NativeValidityState, ValidityState, ValidityTransform,
+    set_validity_handlers, validators::Validators, validity_state::ValidityStateJS,
+    NativeValidityState, ValidityState, ValidityTransform,
 };
 use crate::utils::WeakComponentLink;
-use crate::{bool_to_option, event_into_details, to_option_string};
+use crate::{event_into_details, StaticCallback};
+use dioxus::prelude::*;
 use gloo::events::EventListener;
 use wasm_bindgen::prelude::*;
 use web_sys::Node;
-use yew::prelude::*;
-use yew::virtual_dom::AttrValue;
 
 #[wasm_bindgen(module = "/build/mwc-select.js")]
 extern "C" {
@@ -22,7 +25,16 @@ extern "C" {
     fn _dummy_loader() -> JsValue;
 
     #[wasm_bindgen(method)]
-    fn select(this: &Select, index: usize);
+    fn select(this: &Select, index: i32);
+
+    #[wasm_bindgen(method, getter)]
+    fn index(this: &Select) -> i32;
+
+    #[wasm_bindgen(method, getter)]
+    fn value(this: &Select) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    fn set_value(this: &Select, val: &str);
 
     #[wasm_bindgen(method, setter = validityTransform)]
     fn set_validity_transform(
@@ -33,205 +45,240 @@ extern "C" {
 
 loader_hack!(Select);
 
-/// The `mwc-select` component
-///
-/// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/select)
-pub struct MatSelect {
-    node_ref: NodeRef,
-    validity_transform_closure:
-        Option<Closure<dyn Fn(String, NativeValidityState) -> ValidityStateJS>>,
-    opened_listener: Option<EventListener>,
-    closed_listener: Option<EventListener>,
-    action_listener: Option<EventListener>,
-    selected_listener: Option<EventListener>,
-}
-
 /// Props for [`MatSelect`]
 ///
 /// MWC Documentation:
 ///
 /// - [Properties](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/select#propertiesattributes)
 /// - [Events](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/select#events)
-#[derive(Properties, PartialEq, Clone)]
-pub struct Props {
-    #[prop_or_default]
-    pub value: Option<AttrValue>,
-    #[prop_or_default]
-    pub label: Option<AttrValue>,
-    #[prop_or_default]
+#[derive(Props)]
+pub struct SelectProps<'a> {
+    /// The value of the selected item. Changing this prop selects the item with
+    /// a matching `value`.
+    #[props(into)]
+    pub value: Option<String>,
+    #[props(into)]
+    pub label: Option<String>,
+    #[props(default)]
     pub natural_menu_width: bool,
-    #[prop_or_default]
-    pub icon: Option<AttrValue>,
-    #[prop_or_default]
+    #[props(default)]
+    pub fixed_menu_position: bool,
+    #[props(into)]
+    pub icon: Option<String>,
+    #[props(default)]
     pub disabled: bool,
-    #[prop_or_default]
+    #[props(default)]
     pub outlined: bool,
-    #[prop_or_default]
-    pub helper: Option<AttrValue>,
-    #[prop_or_default]
+    #[props(into)]
+    pub helper: Option<String>,
+    #[props(default)]
     pub required: bool,
-    #[prop_or_default]
-    pub validation_message: Option<AttrValue>,
-    #[prop_or_default]
-    pub items: Option<AttrValue>,
-    #[prop_or(- 1)]
-    pub index: i64,
-    #[prop_or_default]
+    #[props(into)]
+    pub validation_message: Option<String>,
+    #[props(default)]
     pub validity_transform: Option<ValidityTransform>,
-    #[prop_or_default]
+    /// Validation rules, checked after the native validation. The message of
+    /// the first failing rule replaces `validation_message`. Takes precedence
+    /// over `validity_transform`.
+    #[props(default)]
+    pub validators: Option<Validators>,
+    #[props(default)]
     pub validate_on_initial_render: bool,
-    #[prop_or_default]
-    pub children: Children,
-    /// [`WeakComponentLink`] for `MatList` which provides the following methods
-    /// - ```select(&self)```
-    ///
-    /// See [`WeakComponentLink`] documentation for more information
-    #[prop_or_default]
-    pub select_link: WeakComponentLink<MatSelect>,
+    #[props(into)]
+    pub name: Option<String>,
     /// Binds to `opened` event on `mwc-select-surface`
     ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub onopened: Callback<()>,
+    #[props(into)]
+    // the name cannot start with `on` or dioxus will expect an `EventHandler` which aren't static
+    // and thus cannot be used here
+    pub _onopened: Option<StaticCallback<()>>,
     /// Binds to `closed` event on `mwc-select-surface`
     ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub onclosed: Callback<()>,
+    #[props(into)]
+    pub _onclosed: Option<StaticCallback<()>>,
     /// Binds to `action` event on `mwc-list`
     ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub onaction: Callback<ActionDetail>,
+    #[props(into)]
+    pub _onaction: Option<StaticCallback<ActionDetail>>,
     /// Binds to `selected` event on `mwc-list`
     ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub onselected: Callback<SelectedDetail>,
-}
+    #[props(into)]
+    pub _onselected: Option<StaticCallback<SelectedDetail>>,
+    /// Binds to `invalid` event on `mwc-select`
+    ///
+    /// The argument passed to callback is the current validity, including
+    /// the result of `validity_transform`. Fired when a validity check finds
+    /// the select invalid.
+    #[props(into)]
+    pub _oninvalid: Option<StaticCallback<ValidityState>>,
+    /// Called with the new validity whenever it changes after a `change` or
+    /// `invalid` event.
+    #[props(into)]
+    pub _onvalidity_change: Option<StaticCallback<ValidityState>>,
+    /// [`SelectLink`] for calling methods of this select
+    #[props(default)]
+    pub select_link: SelectLink,
+    pub children: Element<'a>,
 
-impl Component for MatSelect {
-    type Message = ();
-    type Properties = Props;
-
-    fn create(ctx: &Context<Self>) -> Self {
-        ctx.props()
-            .select_link
-            .borrow_mut()
-            .replace(ctx.link().clone());
-        Select::ensure_loaded();
-        Self {
-            node_ref: NodeRef::default(),
-            validity_transform_closure: None,
-            opened_listener: None,
-            closed_listener: None,
-            action_listener: None,
-            selected_listener: None,
-        }
-    }
+    #[props(into, default)]
+    pub style: String,
+    #[props(into, default)]
+    pub class: String,
+    #[props(into)]
+    pub slot: Option<String>,
+    #[props(default)]
+    pub dialog_initial_focus: bool,
+}
 
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props();
-        html! {
-             <mwc-select
-                 value={props.value.clone()}
-                 label={props.label.clone()}
-                 naturalMenuWidth={bool_to_option(props.natural_menu_width)}
-                 icon={props.icon.clone()}
-                 disabled={props.disabled}
-                 outlined={bool_to_option(props.outlined)}
-                 helper={props.helper.clone()}
-                 required={props.required}
-                 validationMessage={props.validation_message.clone()}
-                 items={props.items.clone()}
-                 index={to_option_string(props.index)}
-                 validateOnInitialRender={bool_to_option(props.validate_on_initial_render)}
-                 ref={self.node_ref.clone()}
-             >
-               {props.children.clone()}
-             </mwc-select>
+fn render<'a>(cx: Scope<'a, SelectProps<'a>>) -> Element<'a> {
+    let id = crate::use_id(cx, "select");
+    let opened_listener = cx.use_hook(|| None);
+    let closed_listener = cx.use_hook(|| None);
+    let action_listener = cx.use_hook(|| None);
+    let selected_listener = cx.use_hook(|| None);
+    let validity_transform_closure = cx.use_hook(|| None);
+    // read by the `validators` closure, which is only created once
+    let validation_message = cx.use_hook(|| Rc::new(RefCell::new(None)));
+    let validity_listeners = cx.use_hook(Vec::new);
+    let last_validity = cx.use_hook(|| Rc::new(Cell::new(None)));
+    #[cfg(feature = "form")]
+    let form_field = crate::form::field::use_form_field(cx, id, cx.props.name.as_deref(), || {
+        cx.props.value.clone().map(Into::into)
+    });
+    cx.props.select_link.0.set_id(id);
+    validation_message.replace(cx.props.validation_message.clone());
+    if let Some(elem) = crate::get_elem_by_id(id) {
+        let target = elem.clone();
+        let select = JsValue::from(elem).dyn_into::<Select>().unwrap();
+        #[cfg(feature = "form")]
+        let form_value = form_field.map(|field| {
+            field.bind(&target, &["change"], crate::form::field::read_text);
+            field.value().to_string()
+        });
+        #[cfg(not(feature = "form"))]
+        let form_value = None::<String>;
+        if let Some(value) = form_value.as_ref().or(cx.props.value.as_ref()) {
+            select.set_value(value);
         }
-    }
-
-    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
-        // clear event listeners and update link in case the props changed
-        self.opened_listener = None;
-        self.closed_listener = None;
-        self.action_listener = None;
-        self.selected_listener = None;
-        ctx.props()
-            .select_link
-            .borrow_mut()
-            .replace(ctx.link().clone());
-        true
-    }
-
-    //noinspection DuplicatedCode
-    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
-        let props = ctx.props();
-        let element = self.node_ref.cast::<Select>().unwrap();
-        if first_render {
-            if let Some(transform) = props.validity_transform.clone() {
-                self.validity_transform_closure = Some(Closure::wrap(Box::new(
-                    move |s: String, v: NativeValidityState| -> ValidityStateJS {
-                        transform.0(s, v).into()
-                    },
-                )
-                    as Box<dyn Fn(String, NativeValidityState) -> ValidityStateJS>));
-                element.set_validity_transform(self.validity_transform_closure.as_ref().unwrap());
-            }
+        if let Some(listener) = cx.props._onopened.clone() {
+            *opened_listener = Some(EventListener::new(&target, "opened", move |_| {
+                listener.call(())
+            }));
         }
-
-        if self.opened_listener.is_none() {
-            let onopened = props.onopened.clone();
-            self.opened_listener = Some(EventListener::new(&element, "opened", move |_| {
-                onopened.emit(())
+        if let Some(listener) = cx.props._onclosed.clone() {
+            *closed_listener = Some(EventListener::new(&target, "closed", move |_| {
+                listener.call(())
             }));
         }
-
-        if self.closed_listener.is_none() {
-            let onclosed = props.onclosed.clone();
-            self.closed_listener = Some(EventListener::new(&element, "closed", move |_| {
-                onclosed.emit(())
+        if let Some(listener) = cx.props._onaction.clone() {
+            *action_listener = Some(EventListener::new(&target, "action", move |event| {
+                listener.call(ActionDetail::from(event_into_details(event)))
             }));
         }
-
-        if self.action_listener.is_none() {
-            let on_action = props.onaction.clone();
-            self.action_listener = Some(EventListener::new(&element, "action", move |event| {
-                on_action.emit(ActionDetail::from(event_into_details(event)))
+        if let Some(listener) = cx.props._onselected.clone() {
+            *selected_listener = Some(EventListener::new(&target, "selected", move |event| {
+                listener.call(SelectedDetail::from(event_into_details(event)))
             }));
         }
+        *validity_listeners = set_validity_handlers(
+            &target,
+            &["change"],
+            cx.props._oninvalid.clone(),
+            cx.props._onvalidity_change.clone(),
+            last_validity,
+        );
+        if let (Some(validators), None) = (cx.props.validators.clone(), &validity_transform_closure)
+        {
+            *validity_transform_closure =
+                Some(validators.closure(target.clone(), Rc::clone(validation_message)));
+            select.set_validity_transform(validity_transform_closure.as_ref().unwrap());
+        }
+        if let (Some(transform), None) = (
+            cx.props.validity_transform.clone(),
+            &validity_transform_closure,
+        ) {
+            *validity_transform_closure = Some(Closure::wrap(Box::new(
+                move |s: String, v: NativeValidityState| -> ValidityStateJS {
+                    transform.0(s, v).into()
+                },
+            )
+                as Box<dyn Fn(String, NativeValidityState) -> ValidityStateJS>));
+            select.set_validity_transform(validity_transform_closure.as_ref().unwrap());
+        }
+    }
 
-        if self.selected_listener.is_none() {
-            let on_selected = props.onselected.clone();
-            self.selected_listener = Some(EventListener::new(&element, "selected", move |event| {
-                on_selected.emit(SelectedDetail::from(event_into_details(event)))
-            }));
+    render! {
+        mwc-select {
+            id: id,
+
+            label: optional_string_attr!(cx.props.label),
+            naturalMenuWidth: bool_attr!(cx.props.natural_menu_width),
+            fixedMenuPosition: bool_attr!(cx.props.fixed_menu_position),
+            icon: optional_string_attr!(cx.props.icon),
+            disabled: bool_attr!(cx.props.disabled),
+            outlined: bool_attr!(cx.props.outlined),
+            helper: optional_string_attr!(cx.props.helper),
+            required: bool_attr!(cx.props.required),
+            validationMessage: optional_string_attr!(cx.props.validation_message),
+            validateOnInitialRender: bool_attr!(cx.props.validate_on_initial_render),
+            name: optional_string_attr!(cx.props.name),
+
+            style: string_attr!(cx.props.style),
+            class: string_attr!(cx.props.class),
+            slot: optional_string_attr!(cx.props.slot),
+            dialogInitialFocus: bool_attr!(cx.props.dialog_initial_focus),
+
+            &cx.props.children
         }
     }
 }
 
-impl WeakComponentLink<MatSelect> {
-    pub fn select(&self, val: usize) {
-        let c = self
-            .borrow()
-            .as_ref()
-            .unwrap()
-            .get_component()
-            .unwrap()
-            .node_ref
-            .clone();
-        let select_element = c.cast::<Select>().unwrap();
-        select_element.select(val);
+component!('a, MatSelect, SelectProps, render, Select, "select");
+
+/// Link to a rendered [`MatSelect`], passed to it via
+/// [`SelectProps::select_link`].
+///
+/// The link should be created once per component, e.g. with
+/// `cx.use_hook(SelectLink::default)`. Calling methods while the select is
+/// not mounted has no effect.
+#[derive(Clone, Default, PartialEq)]
+pub struct SelectLink(WeakComponentLink);
+
+impl SelectLink {
+    /// Binds to `select` method.
+    ///
+    /// Passing `None` clears the selection.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/select#methods) for details
+    pub fn select(&self, index: Option<usize>) {
+        if let Some(select) = self.0.cast::<Select>() {
+            select.select(index.map_or(-1, |index| index as i32));
+        }
     }
-}
 
-impl MatSelect {
-    /// Returns [`ValidityTransform`] to be passed to `validity_transform` prop
-    pub fn validity_transform<F: Fn(String, NativeValidityState) -> ValidityState + 'static>(
-        func: F,
-    ) -> ValidityTransform {
-        ValidityTransform::new(func)
+    /// Index of the currently selected item, `None` if nothing is selected or
+    /// the select is not mounted.
+    pub fn index(&self) -> Option<usize> {
+        self.0
+            .cast::<Select>()
+            .map(|select| select.index())
+            .and_then(|index| usize::try_from(index).ok())
+    }
+
+    /// Value of the currently selected item, `None` if the select is not
+    /// mounted.
+    pub fn value(&self) -> Option<String> {
+        self.0.cast::<Select>().map(|select| select.value())
+    }
+
+    /// Selects the item with the given value.
+    pub fn set_value(&self, value: &str) {
+        if let Some(select) = self.0.cast::<Select>() {
+            select.set_value(value);
+        }
     }
 }
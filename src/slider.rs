@@ -1,107 +1,154 @@
-use crate::{bool_to_option, to_option_string};
+mod range;
+
+pub use range::{MatRangeSlider, RangeSliderProps, RangeValue};
+
+use std::marker::PhantomData;
+
+use crate::StaticCallback;
+use dioxus::prelude::*;
 use gloo::events::EventListener;
+use js_sys::Object;
 use wasm_bindgen::prelude::*;
-use wasm_bindgen::JsCast;
-use web_sys::{CustomEvent, Element};
-use yew::prelude::*;
+use web_sys::{CustomEvent, Event, Node};
 
-#[wasm_bindgen(module = "/build/mwc-slider.js")]
+#[wasm_bindgen(module = "/build/slider.js")]
 extern "C" {
     #[derive(Debug)]
+    #[wasm_bindgen(extends = Node)]
     type Slider;
 
-    // This needs to be added to each component
     #[wasm_bindgen(getter, static_method_of = Slider)]
     fn _dummy_loader() -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    fn set_value(this: &Slider, value: f64);
 }
 
-// call the macro with the type
 loader_hack!(Slider);
 
-/// The `mwc-snackbar` component
+/// Props for [`MatSlider`], the single thumb `mwc-slider`
 ///
-/// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/slider)
-pub struct MatSlider {
-    node_ref: NodeRef,
-    input_listener: Option<EventListener>,
-    change_listener: Option<EventListener>,
-}
-
-/// Props for [`MatSlider`]
+/// Use [`MatRangeSlider`] for a start and an end thumb.
 ///
 /// MWC Documentation:
 ///
 /// - [Properties](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/slider#propertiesattributes)
 /// - [Events](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/slider#events)
-#[derive(Debug, Properties, PartialEq, Clone)]
-pub struct SliderProps {
-    #[prop_or(0)]
-    pub value: u32,
-    #[prop_or(0)]
-    pub min: u32,
-    #[prop_or(100)]
-    pub max: u32,
-    #[prop_or(1)]
-    pub step: u32,
-    #[prop_or(false)]
-    pub pin: bool,
-    #[prop_or(false)]
-    pub markers: bool,
-    /// Binds to input on `mwc-slider`
-    /// Type passed to callback is `CustomEvent` because `Slider` is
-    /// undocumented See: <https://github.com/material-components/material-components-web-components/issues/1848>
-    #[prop_or_default]
-    pub oninput: Callback<CustomEvent>,
-    /// Binds to change on `mwc-slider`
-    /// Type passed to callback is `CustomEvent` because `Slider` is
-    /// undocumented See: <https://github.com/material-components/material-components-web-components/issues/1848>
-    #[prop_or_default]
-    pub onchange: Callback<CustomEvent>,
-}
+#[derive(Props)]
+pub struct SliderProps<'a> {
+    /// The current value. This is applied on every render, so `_oninput` or
+    /// `_onchange` should be used to keep it in sync when the user moves the
+    /// thumb.
+    #[props(default = 0.0)]
+    pub value: f64,
+    #[props(default = 0.0)]
+    pub min: f64,
+    #[props(default = 100.0)]
+    pub max: f64,
+    #[props(default = 1.0)]
+    pub step: f64,
+    #[props(default)]
+    pub disabled: bool,
+    /// Snaps the thumb to `step` increments and shows the value in a pin
+    /// above the thumb while dragging
+    #[props(default)]
+    pub discrete: bool,
+    /// Shows markers for each step on the track, requires `discrete`
+    #[props(default)]
+    pub with_tick_marks: bool,
+    #[props(into)]
+    pub name: Option<String>,
+    /// Binds to `input` event on `mwc-slider`
+    ///
+    /// The argument passed to callback is the new value. Fired continuously
+    /// while the thumb is being dragged.
+    #[props(into)]
+    // the name cannot start with `on` or dioxus will expect an `EventHandler` which aren't static
+    // and thus cannot be used here
+    pub _oninput: Option<StaticCallback<f64>>,
+    /// Binds to `change` event on `mwc-slider`
+    ///
+    /// The argument passed to callback is the new value. Fired once the user
+    /// has committed a value.
+    #[props(into)]
+    pub _onchange: Option<StaticCallback<f64>>,
+    _lifetime: Option<PhantomData<&'a ()>>,
 
-impl Component for MatSlider {
-    type Message = ();
-    type Properties = SliderProps;
+    #[props(into, default)]
+    pub style: String,
+    #[props(into, default)]
+    pub class: String,
+    #[props(into)]
+    pub slot: Option<String>,
+    #[props(default)]
+    pub dialog_initial_focus: bool,
+}
 
-    fn create(_: &Context<Self>) -> Self {
-        Slider::ensure_loaded();
-        Self {
-            node_ref: NodeRef::default(),
-            input_listener: None,
-            change_listener: None,
+fn render<'a>(cx: Scope<'a, SliderProps<'a>>) -> Element<'a> {
+    let id = crate::use_id(cx, "slider");
+    let input_listener = cx.use_hook(|| None);
+    let change_listener = cx.use_hook(|| None);
+    if let Some(elem) = crate::get_elem_by_id(id) {
+        let target = elem.clone();
+        let slider = JsValue::from(elem).dyn_into::<Slider>().unwrap();
+        slider.set_value(cx.props.value);
+        if let Some(listener) = cx.props._oninput.clone() {
+            *input_listener = Some(EventListener::new(&target, "input", move |event| {
+                if let Some(value) = value_from_event(event) {
+                    listener.call(value)
+                }
+            }));
         }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props();
-        html! {
-             <mwc-slider
-                 value={to_option_string(props.value)}
-                 min={to_option_string(props.min)}
-                 max={to_option_string(props.max)}
-                 step={to_option_string(props.step)}
-                 pin={bool_to_option(props.pin)}
-                 markers={bool_to_option(props.markers)}
-                 ref={self.node_ref.clone()}
-             ></mwc-slider>
+        if let Some(listener) = cx.props._onchange.clone() {
+            *change_listener = Some(EventListener::new(&target, "change", move |event| {
+                if let Some(value) = value_from_event(event) {
+                    listener.call(value)
+                }
+            }));
         }
     }
 
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        let props = ctx.props();
-        let element = self.node_ref.cast::<Element>().unwrap();
-        if self.input_listener.is_none() {
-            let oninput = props.oninput.clone();
-            self.input_listener = Some(EventListener::new(&element, "input", move |event| {
-                oninput.emit(JsValue::from(event).unchecked_into::<CustomEvent>())
-            }));
-        };
+    render! {
+        mwc-slider {
+            id: id,
 
-        if self.change_listener.is_none() {
-            let onchange = props.onchange.clone();
-            self.change_listener = Some(EventListener::new(&element, "change", move |event| {
-                onchange.emit(JsValue::from(event).unchecked_into::<CustomEvent>())
-            }));
+            value: cx.props.value,
+            min: cx.props.min,
+            max: cx.props.max,
+            step: cx.props.step,
+            disabled: bool_attr!(cx.props.disabled),
+            discrete: bool_attr!(cx.props.discrete),
+            withTickMarks: bool_attr!(cx.props.with_tick_marks),
+            name: optional_string_attr!(cx.props.name),
+
+            style: string_attr!(cx.props.style),
+            class: string_attr!(cx.props.class),
+            slot: optional_string_attr!(cx.props.slot),
+            dialogInitialFocus: bool_attr!(cx.props.dialog_initial_focus),
         }
     }
 }
+
+component!('a, MatSlider, SliderProps, render, Slider, "slider");
+
+/// The value of a `CustomEvent` fired by `mwc-slider`. The native `input`
+/// events of the `<input type="range">` inside its shadow DOM, e.g. when
+/// using the keyboard, bubble out as well and are ignored.
+fn value_from_event(event: &Event) -> Option<f64> {
+    event
+        .dyn_ref::<CustomEvent>()
+        .map(|event| event.detail().unchecked_into::<ValueDetailJS>().value())
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug)]
+    #[wasm_bindgen(extends = Object)]
+    type ValueDetailJS;
+
+    #[wasm_bindgen(method, getter)]
+    fn value(this: &ValueDetailJS) -> f64;
+
+    #[wasm_bindgen(method, getter)]
+    fn thumb(this: &ValueDetailJS) -> u8;
+}
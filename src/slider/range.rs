@@ -0,0 +1,182 @@
+use std::marker::PhantomData;
+
+use crate::StaticCallback;
+use dioxus::prelude::*;
+use gloo::events::EventListener;
+use wasm_bindgen::prelude::*;
+use web_sys::{Event, Node};
+
+use super::ValueDetailJS;
+
+#[wasm_bindgen(module = "/build/slider-range.js")]
+extern "C" {
+    #[derive(Debug, Clone)]
+    #[wasm_bindgen(extends = Node)]
+    type SliderRange;
+
+    #[wasm_bindgen(getter, static_method_of = SliderRange)]
+    fn _dummy_loader() -> JsValue;
+
+    #[wasm_bindgen(method, getter = valueStart)]
+    fn value_start(this: &SliderRange) -> f64;
+
+    #[wasm_bindgen(method, setter = valueStart)]
+    fn set_value_start(this: &SliderRange, value: f64);
+
+    #[wasm_bindgen(method, getter = valueEnd)]
+    fn value_end(this: &SliderRange) -> f64;
+
+    #[wasm_bindgen(method, setter = valueEnd)]
+    fn set_value_end(this: &SliderRange, value: f64);
+}
+
+loader_hack!(SliderRange);
+
+/// The values of both thumbs of a [`MatRangeSlider`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RangeValue {
+    pub start: f64,
+    pub end: f64,
+}
+
+/// Props for [`MatRangeSlider`], the `mwc-slider-range` with a start and an
+/// end thumb
+///
+/// MWC Documentation:
+///
+/// - [Properties](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/slider#propertiesattributes)
+/// - [Events](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/slider#events)
+#[derive(Props)]
+pub struct RangeSliderProps<'a> {
+    /// The value of the start thumb. Like `value_end`, this is applied on every
+    /// render, so `_oninput` or `_onchange` should be used to keep it in sync
+    /// when the user moves a thumb. Clamped to `value_end`.
+    #[props(default = 0.0)]
+    pub value_start: f64,
+    /// The value of the end thumb
+    #[props(default = 100.0)]
+    pub value_end: f64,
+    #[props(default = 0.0)]
+    pub min: f64,
+    #[props(default = 100.0)]
+    pub max: f64,
+    #[props(default = 1.0)]
+    pub step: f64,
+    #[props(default)]
+    pub disabled: bool,
+    /// Snaps the thumbs to `step` increments and shows the values in pins
+    /// above the thumbs while dragging
+    #[props(default)]
+    pub discrete: bool,
+    /// Shows markers for each step on the track, requires `discrete`
+    #[props(default)]
+    pub with_tick_marks: bool,
+    /// The values are submitted as `<name>-start` and `<name>-end`
+    #[props(into)]
+    pub name: Option<String>,
+    /// Binds to `input` event on `mwc-slider-range`
+    ///
+    /// The argument passed to callback is the new start and end value. Fired
+    /// continuously while a thumb is being dragged.
+    #[props(into)]
+    // the name cannot start with `on` or dioxus will expect an `EventHandler` which aren't static
+    // and thus cannot be used here
+    pub _oninput: Option<StaticCallback<RangeValue>>,
+    /// Binds to `change` event on `mwc-slider-range`
+    ///
+    /// The argument passed to callback is the new start and end value. Fired
+    /// once the user has committed a value.
+    #[props(into)]
+    pub _onchange: Option<StaticCallback<RangeValue>>,
+    _lifetime: Option<PhantomData<&'a ()>>,
+
+    #[props(into, default)]
+    pub style: String,
+    #[props(into, default)]
+    pub class: String,
+    #[props(into)]
+    pub slot: Option<String>,
+    #[props(default)]
+    pub dialog_initial_focus: bool,
+}
+
+fn render<'a>(cx: Scope<'a, RangeSliderProps<'a>>) -> Element<'a> {
+    let id = crate::use_id(cx, "slider-range");
+    let input_listener = cx.use_hook(|| None);
+    let change_listener = cx.use_hook(|| None);
+    if let Some(elem) = crate::get_elem_by_id(id) {
+        let target = elem.clone();
+        let slider = JsValue::from(elem).dyn_into::<SliderRange>().unwrap();
+        slider.set_value_start(cx.props.value_start);
+        slider.set_value_end(cx.props.value_end);
+        if let Some(listener) = cx.props._oninput.clone() {
+            let slider = slider.clone();
+            *input_listener = Some(EventListener::new(&target, "input", move |event| {
+                if let Some(value) = range_value_from_event(&slider, event) {
+                    listener.call(value)
+                }
+            }));
+        }
+        if let Some(listener) = cx.props._onchange.clone() {
+            *change_listener = Some(EventListener::new(&target, "change", move |event| {
+                if let Some(value) = range_value_from_event(&slider, event) {
+                    listener.call(value)
+                }
+            }));
+        }
+    }
+
+    render! {
+        mwc-slider-range {
+            id: id,
+
+            valueStart: cx.props.value_start,
+            valueEnd: cx.props.value_end,
+            min: cx.props.min,
+            max: cx.props.max,
+            step: cx.props.step,
+            disabled: bool_attr!(cx.props.disabled),
+            discrete: bool_attr!(cx.props.discrete),
+            withTickMarks: bool_attr!(cx.props.with_tick_marks),
+            name: optional_string_attr!(cx.props.name),
+
+            style: string_attr!(cx.props.style),
+            class: string_attr!(cx.props.class),
+            slot: optional_string_attr!(cx.props.slot),
+            dialogInitialFocus: bool_attr!(cx.props.dialog_initial_focus),
+        }
+    }
+}
+
+/// The `mwc-slider-range` component
+///
+/// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/slider)
+#[allow(non_snake_case)]
+pub fn MatRangeSlider<'a>(cx: Scope<'a, RangeSliderProps<'a>>) -> Element<'a> {
+    SliderRange::ensure_loaded();
+    render(cx)
+}
+
+/// The values of both thumbs after a `CustomEvent` fired by
+/// `mwc-slider-range`. The event only carries the value of the moved thumb,
+/// the other one is read from the slider. Native events of the inputs inside
+/// its shadow DOM are ignored.
+fn range_value_from_event(slider: &SliderRange, event: &Event) -> Option<RangeValue> {
+    let detail = event
+        .dyn_ref::<web_sys::CustomEvent>()?
+        .detail()
+        .unchecked_into::<ValueDetailJS>();
+    Some(match detail.thumb() {
+        THUMB_START => RangeValue {
+            start: detail.value(),
+            end: slider.value_end(),
+        },
+        _ => RangeValue {
+            start: slider.value_start(),
+            end: detail.value(),
+        },
+    })
+}
+
+/// `Thumb.START` of MWC, `Thumb.END` is 2
+const THUMB_START: u8 = 1;
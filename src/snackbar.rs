@@ -1,11 +1,11 @@
-use crate::{bool_to_option, event_into_details, to_option_string, WeakComponentLink};
+use dioxus::prelude::*;
 use gloo::events::EventListener;
 use js_sys::Object;
 use wasm_bindgen::prelude::*;
-use wasm_bindgen::JsCast;
 use web_sys::Node;
-use yew::prelude::*;
-use yew::virtual_dom::AttrValue;
+
+use crate::utils::WeakComponentLink;
+use crate::{event_into_details, StaticCallback};
 
 #[wasm_bindgen(module = "/build/mwc-snackbar.js")]
 extern "C" {
@@ -33,194 +33,166 @@ extern "C" {
     type DetailsReason;
 
     #[wasm_bindgen(method, getter)]
-    fn reason(this: &DetailsReason) -> String;
+    fn reason(this: &DetailsReason) -> Option<String>;
 }
 
 loader_hack!(Snackbar);
 
-/// The `mwc-snackbar` component
-///
-/// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/snackbar)
-pub struct MatSnackbar {
-    node_ref: NodeRef,
-    opening_listener: Option<EventListener>,
-    opened_listener: Option<EventListener>,
-    closing_listener: Option<EventListener>,
-    closed_listener: Option<EventListener>,
-}
-
 /// Props for [`MatSnackbar`]
 ///
 /// MWC Documentation:
 ///
 /// - [Properties](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/snackbar#propertiesattributes)
 /// - [Events](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/snackbar#events)
-#[derive(Properties, PartialEq, Clone)]
-pub struct SnackbarProps {
-    #[prop_or_default]
+#[derive(Props)]
+pub struct SnackbarProps<'a> {
+    #[props(default)]
     pub open: bool,
-    #[prop_or(5000)]
-    pub timeout_ms: i32,
-    #[prop_or_default]
+    #[props(default = 5000)]
+    pub timeout_ms: i64,
+    #[props(default)]
     pub close_on_escape: bool,
-    #[prop_or_default]
-    pub label_text: Option<AttrValue>,
-    #[prop_or_default]
+    #[props(into)]
+    pub label_text: Option<String>,
+    #[props(default)]
     pub stacked: bool,
-    #[prop_or_default]
+    #[props(default)]
     pub leading: bool,
     /// Binds to `MDCSnackbar:opening` event
     ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub onopening: Callback<()>,
+    #[props(into)]
+    // the name cannot start with `on` or dioxus will expect an `EventHandler` which aren't static
+    // and thus cannot be used here
+    pub _onopening: Option<StaticCallback<()>>,
     /// Binds to `MDCSnackbar:opened` event
     ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub onopened: Callback<()>,
-    /// Binds to `MDCSnackbar:` event
+    #[props(into)]
+    pub _onopened: Option<StaticCallback<()>>,
+    /// Binds to `MDCSnackbar:closing` event
     ///
     /// The argument passed to callback corresponds to `reason` parameter of the
     /// event
     ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub onclosing: Callback<Option<String>>,
-    /// Binds to `closing` event
+    #[props(into)]
+    pub _onclosing: Option<StaticCallback<Option<String>>>,
+    /// Binds to `MDCSnackbar:closed` event
     ///
     /// The argument passed to callback corresponds to `reason` parameter of the
     /// event
     ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub onclosed: Callback<Option<String>>,
-    /// [`WeakComponentLink`] for `MatList` which provides the following methods
-    /// - ```show(&self)```
-    /// - ```close(&self, reason: &str)```
-    ///
-    /// See [`WeakComponentLink`] documentation for more information
-    #[prop_or_default]
-    pub snackbar_link: WeakComponentLink<MatSnackbar>,
-    #[prop_or_default]
-    pub children: Children,
+    #[props(into)]
+    pub _onclosed: Option<StaticCallback<Option<String>>>,
+    /// [`SnackbarLink`] for calling methods of this snackbar
+    #[props(default)]
+    pub snackbar_link: SnackbarLink,
+    #[props(default)]
+    pub children: Element<'a>,
+
+    #[props(into, default)]
+    pub style: String,
+    #[props(into, default)]
+    pub class: String,
 }
 
-impl Component for MatSnackbar {
-    type Message = ();
-    type Properties = SnackbarProps;
-
-    fn create(ctx: &Context<Self>) -> Self {
-        ctx.props()
-            .snackbar_link
-            .borrow_mut()
-            .replace(ctx.link().clone());
-        Snackbar::ensure_loaded();
-        Self {
-            node_ref: NodeRef::default(),
-            opening_listener: None,
-            opened_listener: None,
-            closing_listener: None,
-            closed_listener: None,
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props();
-        html! {
-             <mwc-snackbar
-                 timeoutMs={to_option_string(props.timeout_ms)}
-                 closeOnEscape={to_option_string(props.close_on_escape)}
-                 labelText={props.label_text.clone()}
-                 stacked={bool_to_option(props.stacked)}
-                 leading={bool_to_option(props.leading)}
-                 ref={self.node_ref.clone()}
-             >{props.children.clone()}</mwc-snackbar>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        let props = ctx.props();
-        let element = self.node_ref.cast::<Snackbar>().unwrap();
-        element.set_open(props.open);
-
-        if self.opening_listener.is_none() {
-            let on_opening = props.onopening.clone();
-            self.opening_listener = Some(EventListener::new(
-                &element,
+fn render<'a>(cx: Scope<'a, SnackbarProps<'a>>) -> Element<'a> {
+    let id = crate::use_id(cx, "snackbar");
+    let opening_listener = cx.use_hook(|| None);
+    let opened_listener = cx.use_hook(|| None);
+    let closing_listener = cx.use_hook(|| None);
+    let closed_listener = cx.use_hook(|| None);
+    cx.props.snackbar_link.0.set_id(id);
+    if let Some(elem) = crate::get_elem_by_id(id) {
+        let target = elem.clone();
+        let snackbar = JsValue::from(elem).dyn_into::<Snackbar>().unwrap();
+        snackbar.set_open(cx.props.open);
+        if let Some(listener) = cx.props._onopening.clone() {
+            *opening_listener = Some(EventListener::new(
+                &target,
                 "MDCSnackbar:opening",
-                move |_| {
-                    on_opening.emit(());
-                },
+                move |_| listener.call(()),
             ));
-        };
-
-        if self.opened_listener.is_none() {
-            let on_opened = props.onopened.clone();
-            self.opened_listener = Some(EventListener::new(
-                &element,
+        }
+        if let Some(listener) = cx.props._onopened.clone() {
+            *opened_listener = Some(EventListener::new(
+                &target,
                 "MDCSnackbar:opened",
-                move |_| {
-                    on_opened.emit(());
-                },
+                move |_| listener.call(()),
             ));
-        };
-
-        if self.closing_listener.is_none() {
-            let on_closing = props.onclosing.clone();
-            self.closing_listener = Some(EventListener::new(
-                &element,
+        }
+        if let Some(listener) = cx.props._onclosing.clone() {
+            *closing_listener = Some(EventListener::new(
+                &target,
                 "MDCSnackbar:closing",
-                move |event| {
-                    on_closing.emit(event_into_details_reason(event));
-                },
+                move |event| listener.call(event_into_details_reason(event)),
             ));
         }
-
-        if self.closed_listener.is_none() {
-            let on_closed = props.onclosed.clone();
-            self.closed_listener = Some(EventListener::new(
-                &element,
+        if let Some(listener) = cx.props._onclosed.clone() {
+            *closed_listener = Some(EventListener::new(
+                &target,
                 "MDCSnackbar:closed",
-                move |event| {
-                    on_closed.emit(event_into_details_reason(event));
-                },
+                move |event| listener.call(event_into_details_reason(event)),
             ));
         }
     }
+
+    render! {
+        mwc-snackbar {
+            id: id,
+
+            timeoutMs: cx.props.timeout_ms,
+            closeOnEscape: bool_attr!(cx.props.close_on_escape),
+            labelText: optional_string_attr!(cx.props.label_text),
+            stacked: bool_attr!(cx.props.stacked),
+            leading: bool_attr!(cx.props.leading),
+
+            style: string_attr!(cx.props.style),
+            class: string_attr!(cx.props.class),
+
+            &cx.props.children
+        }
+    }
 }
 
-impl WeakComponentLink<MatSnackbar> {
+component!('a, MatSnackbar, SnackbarProps, render, Snackbar, "snackbar");
+
+/// Link to a rendered [`MatSnackbar`], passed to it via
+/// [`SnackbarProps::snackbar_link`].
+///
+/// The link should be created once per component, e.g. with
+/// `cx.use_hook(SnackbarLink::default)`. Calling methods while the snackbar
+/// is not mounted has no effect.
+#[derive(Clone, Default, PartialEq)]
+pub struct SnackbarLink(WeakComponentLink);
+
+impl SnackbarLink {
+    /// Binds to `show` method.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/snackbar#methods) for details
     pub fn show(&self) {
-        self.borrow()
-            .as_ref()
-            .unwrap()
-            .get_component()
-            .unwrap()
-            .node_ref
-            .cast::<Snackbar>()
-            .unwrap()
-            .show()
+        if let Some(snackbar) = self.0.cast::<Snackbar>() {
+            snackbar.show();
+        }
     }
 
+    /// Binds to `close` method.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/snackbar#methods) for details
     pub fn close(&self, reason: &str) {
-        self.borrow()
-            .as_ref()
-            .unwrap()
-            .get_component()
-            .unwrap()
-            .node_ref
-            .cast::<Snackbar>()
-            .unwrap()
-            .close(&JsValue::from_str(reason))
+        if let Some(snackbar) = self.0.cast::<Snackbar>() {
+            snackbar.close(&JsValue::from_str(reason));
+        }
     }
 }
 
-fn event_into_details_reason(event: &Event) -> Option<String> {
+fn event_into_details_reason(event: &web_sys::Event) -> Option<String> {
     let details: JsValue = event_into_details(event);
     if details.is_undefined() {
         None
     } else {
-        Some(details.unchecked_into::<DetailsReason>().reason())
+        details.unchecked_into::<DetailsReason>().reason()
     }
 }
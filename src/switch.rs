@@ -29,6 +29,10 @@ pub struct SwitchProps<'a> {
     pub selected: bool,
     #[props(default)]
     pub disabled: bool,
+    /// Whether the switch must be selected when a surrounding `MatForm` is
+    /// submitted. Not validated by the switch itself.
+    #[props(default)]
+    pub required: bool,
     #[props(into)]
     pub name: Option<String>,
     #[props(into)]
@@ -52,8 +56,20 @@ pub struct SwitchProps<'a> {
 fn render<'a>(cx: Scope<'a, SwitchProps<'a>>) -> Element<'a> {
     let id = crate::use_id(cx, "switch");
     let click_listener = cx.use_hook(|| None);
+    #[cfg(feature = "form")]
+    let form_field = crate::form::field::use_form_field(cx, id, cx.props.name.as_deref(), || {
+        Some(cx.props.selected.into())
+    });
     if let Some(elem) = crate::get_elem_by_id(id) {
         let target = elem;
+        #[cfg(feature = "form")]
+        if let Some(field) = form_field {
+            field.bind(&target, &["click"], |elem| {
+                crate::form::field::read_bool(elem, "selected")
+            });
+            let selected = field.value().as_bool().unwrap_or_default();
+            js_sys::Reflect::set(&target, &"selected".into(), &selected.into()).unwrap();
+        }
         if let Some(listener) = cx.props._onclick.clone() {
             *click_listener = Some(EventListener::new(&target, "click", move |_| {
                 listener.call(())
@@ -67,6 +83,7 @@ fn render<'a>(cx: Scope<'a, SwitchProps<'a>>) -> Element<'a> {
 
             selected: bool_attr!(cx.props.selected),
             disabled: bool_attr!(cx.props.disabled),
+            required: bool_attr!(cx.props.required),
             name: optional_string_attr!(cx.props.name),
             value: optional_string_attr!(cx.props.value),
 
@@ -1,10 +1,8 @@
-use crate::{bool_to_option, event_details_into};
+use crate::{event_details_into, StaticCallback};
+use dioxus::prelude::*;
 use gloo::events::EventListener;
 use js_sys::Object;
 use wasm_bindgen::prelude::*;
-use web_sys::Element;
-use yew::prelude::*;
-use yew::virtual_dom::AttrValue;
 
 #[wasm_bindgen(module = "/build/mwc-tab.js")]
 extern "C" {
@@ -17,92 +15,87 @@ extern "C" {
 
 loader_hack!(Tab);
 
-/// The `mwc-tab` component
-///
-/// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/tab)
-pub struct MatTab {
-    node_ref: NodeRef,
-    interacted_listener: Option<EventListener>,
-}
-
-/// Props for `MatTab`
+/// Props for [`MatTab`]
 ///
 /// MWC Documentation [properties](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/tab#propertiesattributes)
 /// and [events](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/tab#events)
-#[derive(Debug, Properties, PartialEq, Clone)]
-pub struct TabProps {
-    #[prop_or_default]
-    pub label: Option<AttrValue>,
-    #[prop_or_default]
-    pub icon: Option<AttrValue>,
-    #[prop_or_default]
+#[derive(Props)]
+pub struct TabProps<'a> {
+    #[props(into)]
+    pub label: Option<String>,
+    #[props(into)]
+    pub icon: Option<String>,
+    /// Use the content of a [`MatTabIcon`][super::MatTabIcon] child as the
+    /// icon
+    #[props(default)]
     pub has_image_icon: bool,
-    #[prop_or_default]
-    pub indicator_icon: Option<AttrValue>,
-    #[prop_or_default]
+    #[props(into)]
+    pub indicator_icon: Option<String>,
+    #[props(default)]
     pub is_fading_indicator: bool,
-    #[prop_or_default]
+    #[props(default)]
     pub min_width: bool,
-    #[prop_or_default]
+    #[props(default)]
     pub is_min_width_indicator: bool,
-    #[prop_or_default]
+    #[props(default)]
     pub stacked: bool,
     /// Binds to `MDCTab:interacted` event on `mwc-tab`
     ///
+    /// The argument passed to callback is the `id` of the tab element.
+    ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub oninteracted: Callback<String>,
-    #[prop_or_default]
-    pub children: Children,
-}
-
-impl Component for MatTab {
-    type Message = ();
-    type Properties = TabProps;
-
-    fn create(_: &Context<Self>) -> Self {
-        Tab::ensure_loaded();
-        Self {
-            node_ref: NodeRef::default(),
-            interacted_listener: None,
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props();
-        html! {
-             <mwc-tab
-                 label={props.label.clone()}
-                 icon={props.icon.clone()}
-                 hasImageIcon={bool_to_option(props.has_image_icon)}
-                 indicatorIcon={props.indicator_icon.clone()}
-                 isFadingIndicator={bool_to_option(props.is_fading_indicator)}
-                 minWidth={bool_to_option(props.min_width)}
-                 isMinWidthIndicator={bool_to_option(props.is_min_width_indicator)}
-                 stacked={bool_to_option(props.stacked)}
-                 ref={self.node_ref.clone()}
-             >{props.children.clone()}</mwc-tab>
-        }
-    }
+    #[props(into)]
+    // the name cannot start with `on` or dioxus will expect an `EventHandler` which aren't static
+    // and thus cannot be used here
+    pub _oninteracted: Option<StaticCallback<String>>,
+    #[props(default)]
+    pub children: Element<'a>,
 
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        let props = ctx.props();
-        if self.interacted_listener.is_none() {
-            let element = self.node_ref.cast::<Element>().unwrap();
+    #[props(into, default)]
+    pub style: String,
+    #[props(into, default)]
+    pub class: String,
+}
 
-            let on_interacted = props.oninteracted.clone();
-            self.interacted_listener = Some(EventListener::new(
-                &element,
+fn render<'a>(cx: Scope<'a, TabProps<'a>>) -> Element<'a> {
+    let id = crate::use_id(cx, "tab");
+    let interacted_listener = cx.use_hook(|| None);
+    if let Some(elem) = crate::get_elem_by_id(id) {
+        if let Some(listener) = cx.props._oninteracted.clone() {
+            *interacted_listener = Some(EventListener::new(
+                &elem,
                 "MDCTab:interacted",
                 move |event| {
                     let detail = event_details_into::<InteractedDetailJS>(event);
-                    on_interacted.emit(detail.tab_id());
+                    listener.call(detail.tab_id())
                 },
             ));
         }
     }
+
+    render! {
+        mwc-tab {
+            id: id,
+
+            label: optional_string_attr!(cx.props.label),
+            icon: optional_string_attr!(cx.props.icon),
+            hasImageIcon: bool_attr!(cx.props.has_image_icon),
+            indicatorIcon: optional_string_attr!(cx.props.indicator_icon),
+            isFadingIndicator: bool_attr!(cx.props.is_fading_indicator),
+            minWidth: bool_attr!(cx.props.min_width),
+            isMinWidthIndicator: bool_attr!(cx.props.is_min_width_indicator),
+            stacked: bool_attr!(cx.props.stacked),
+
+            style: string_attr!(cx.props.style),
+            class: string_attr!(cx.props.class),
+
+            &cx.props.children
+        }
+    }
 }
 
+component!('a, MatTab, TabProps, render, Tab, "tab");
+
 #[wasm_bindgen]
 extern "C" {
     #[derive(Debug)]
@@ -1,86 +1,89 @@
-use crate::{event_details_into, to_option_string};
+use crate::{event_details_into, StaticCallback};
+use dioxus::prelude::*;
 use gloo::events::EventListener;
 use js_sys::Object;
 use wasm_bindgen::prelude::*;
-use web_sys::Element;
-use yew::prelude::*;
+use web_sys::Node;
 
 #[wasm_bindgen(module = "/build/mwc-tab-bar.js")]
 extern "C" {
     #[derive(Debug)]
+    #[wasm_bindgen(extends = Node)]
     type TabBar;
 
     #[wasm_bindgen(getter, static_method_of = TabBar)]
     fn _dummy_loader() -> JsValue;
+
+    #[wasm_bindgen(method, setter = activeIndex)]
+    fn set_active_index(this: &TabBar, value: usize);
 }
 
 loader_hack!(TabBar);
 
-/// The `mwc-tab-bar` component
-///
-/// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/tab-bar)
-pub struct MatTabBar {
-    node_ref: NodeRef,
-    activated_listener: Option<EventListener>,
-}
-
-/// Props for `MatTabBar`.
+/// Props for [`MatTabBar`]
 ///
 /// MWC Documentation [properties](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/tab-bar#propertiesattributes)
 /// and [events](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/tab-bar#events)
-#[derive(Debug, Properties, PartialEq, Clone)]
-pub struct TabBarProps {
-    #[prop_or_default]
-    pub active_index: u32,
-    /// Binds to `MDCTabBar:activated` event on `mwc-tab`
+#[derive(Props)]
+pub struct TabBarProps<'a> {
+    /// Index of the active tab. This is applied on every render, so
+    /// `_onactivated` should be used to keep it in sync when the user selects
+    /// a tab.
+    #[props(default)]
+    pub active_index: usize,
+    /// Binds to `MDCTabBar:activated` event on `mwc-tab-bar`
+    ///
+    /// The argument passed to callback is the index of the activated tab.
     ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub onactivated: Callback<usize>,
-    #[prop_or_default]
-    pub children: Children,
-}
+    #[props(into)]
+    // the name cannot start with `on` or dioxus will expect an `EventHandler` which aren't static
+    // and thus cannot be used here
+    pub _onactivated: Option<StaticCallback<usize>>,
+    #[props(default)]
+    pub children: Element<'a>,
 
-impl Component for MatTabBar {
-    type Message = ();
-    type Properties = TabBarProps;
-
-    fn create(_: &Context<Self>) -> Self {
-        TabBar::ensure_loaded();
-        Self {
-            node_ref: NodeRef::default(),
-            activated_listener: None,
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props();
-        html! {
-             <mwc-tab-bar
-                 activeIndex={to_option_string(props.active_index)}
-                 ref={self.node_ref.clone()}
-             >{props.children.clone()}</mwc-tab-bar>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        let props = ctx.props();
-        if self.activated_listener.is_none() {
-            let element = self.node_ref.cast::<Element>().unwrap();
+    #[props(into, default)]
+    pub style: String,
+    #[props(into, default)]
+    pub class: String,
+}
 
-            let on_activated = props.onactivated.clone();
-            self.activated_listener = Some(EventListener::new(
-                &element,
+fn render<'a>(cx: Scope<'a, TabBarProps<'a>>) -> Element<'a> {
+    let id = crate::use_id(cx, "tab-bar");
+    let activated_listener = cx.use_hook(|| None);
+    if let Some(elem) = crate::get_elem_by_id(id) {
+        let target = elem.clone();
+        let tab_bar = JsValue::from(elem).dyn_into::<TabBar>().unwrap();
+        tab_bar.set_active_index(cx.props.active_index);
+        if let Some(listener) = cx.props._onactivated.clone() {
+            *activated_listener = Some(EventListener::new(
+                &target,
                 "MDCTabBar:activated",
                 move |event| {
                     let detail = event_details_into::<ActivatedDetailJS>(event);
-                    on_activated.emit(detail.index());
+                    listener.call(detail.index())
                 },
             ));
         }
     }
+
+    render! {
+        mwc-tab-bar {
+            id: id,
+
+            activeIndex: cx.props.active_index as i64,
+
+            style: string_attr!(cx.props.style),
+            class: string_attr!(cx.props.class),
+
+            &cx.props.children
+        }
+    }
 }
 
+component!('a, MatTabBar, TabBarProps, render, TabBar, "tab-bar");
+
 #[wasm_bindgen]
 extern "C" {
     #[derive(Debug)]
@@ -1,50 +1,25 @@
-use yew::prelude::*;
+use dioxus::prelude::*;
 
 const SLOT: &str = "icon";
 
 /// Props for [`MatTabIcon`]
-#[derive(Properties, PartialEq, Clone)]
-pub struct TabIconProps {
-    pub children: Children,
+#[derive(Props)]
+pub struct TabIconProps<'a> {
+    pub children: Element<'a>,
 }
 
-/// Defines title for [`MatTab`][crate::MatTab].
+/// Defines an image icon for [`MatTab`][crate::MatTab].
 ///
-/// If the child passed is an element (a `VTag`), then it is modified to include
-/// the appropriate attributes. Otherwise, the child is wrapped in a `span`
-/// containing said attributes.
-pub struct MatTabIcon {}
-
-impl Component for MatTabIcon {
-    type Message = ();
-    type Properties = TabIconProps;
-
-    fn create(_: &Context<Self>) -> Self {
-        Self {}
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props();
-        let children = props
-            .children
-            .iter()
-            .map(|child| match child {
-                Html::VTag(mut vtag) => {
-                    vtag.add_attribute("slot", "title");
-                    Html::VTag(vtag)
-                }
-                _ => {
-                    html! {
-                         <span slot={SLOT}>
-                             {child}
-                         </span>
-                    }
-                }
-            })
-            .collect::<Html>();
-
-        html! {
-             {children}
+/// Only shown if [`TabProps::has_image_icon`][crate::tabs::TabProps::has_image_icon] is set.
+///
+/// The passed children are wrapped in a `span` with the required attributes
+/// set.
+#[allow(non_snake_case)]
+pub fn MatTabIcon<'a>(cx: Scope<'a, TabIconProps<'a>>) -> Element<'a> {
+    render! {
+        span {
+            slot: SLOT,
+            &cx.props.children
         }
     }
 }
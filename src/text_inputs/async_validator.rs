@@ -0,0 +1,186 @@
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use gloo::events::EventListener;
+use gloo::timers::callback::Timeout;
+use wasm_bindgen::prelude::*;
+use web_sys::Element;
+
+use crate::StaticCallback;
+
+/// The future returned by the check of an [`AsyncValidator`]
+pub type ValidationFuture = Pin<Box<dyn Future<Output = Result<(), String>>>>;
+
+/// A validation that has to wait for a future, e.g. a lookup on a server
+/// whether a username is already taken.
+///
+/// Passed to the `async_validator` prop of [`MatTextField`](crate::MatTextField)
+/// or [`MatTextArea`](crate::MatTextArea). The check runs once the input stopped
+/// for the [`debounce`](Self::debounce) time. While it runs, the field is
+/// pending and shows the [`pending_icon`](Self::pending_icon) as trailing
+/// icon. An `Err` sets `customError` with the returned message, results of
+/// checks for outdated values are discarded. Empty values are not checked.
+///
+/// ```
+/// use material_dioxus::text_inputs::AsyncValidator;
+///
+/// # async fn is_taken(_: &str) -> bool { false }
+/// let validator = AsyncValidator::new(|username| async move {
+///     match is_taken(&username).await {
+///         true => Err(format!("{username} is already taken")),
+///         false => Ok(()),
+///     }
+/// })
+/// .debounce(500);
+/// ```
+#[derive(Clone)]
+pub struct AsyncValidator {
+    check: Rc<dyn Fn(String) -> ValidationFuture>,
+    debounce: u32,
+    pending_icon: Option<String>,
+}
+
+impl AsyncValidator {
+    /// Creates a validator running `check` with the current value, debounced
+    /// by 300 ms.
+    pub fn new<F, Fut>(check: F) -> Self
+    where
+        F: Fn(String) -> Fut + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        Self {
+            check: Rc::new(move |value| Box::pin(check(value))),
+            debounce: 300,
+            pending_icon: Some("hourglass_empty".to_owned()),
+        }
+    }
+
+    /// Sets the time in milliseconds the input has to stop before the check
+    /// runs.
+    pub fn debounce(mut self, millis: u32) -> Self {
+        self.debounce = millis;
+        self
+    }
+
+    /// Sets the trailing icon shown while the check runs, `None` keeps the
+    /// `icon_trailing` of the field. Defaults to `hourglass_empty`.
+    pub fn pending_icon(mut self, icon: Option<&str>) -> Self {
+        self.pending_icon = icon.map(str::to_owned);
+        self
+    }
+
+    /// Creates the `input` listener running the check on `target`.
+    /// `icon_trailing` is restored when the field is no longer pending and
+    /// `validation_message` when the custom error is cleared.
+    pub(crate) fn listen(
+        &self,
+        target: &Element,
+        state: &Rc<AsyncValidation>,
+        icon_trailing: Option<String>,
+        validation_message: Option<String>,
+        onpending: Option<StaticCallback<bool>>,
+    ) -> EventListener {
+        let validator = self.clone();
+        let state = Rc::clone(state);
+        let elem = target.clone();
+        EventListener::new(target, "input", move |_| {
+            let generation = state.generation.get() + 1;
+            state.generation.set(generation);
+            // dropping the timeout cancels it
+            state.timeout.take();
+            // `setCustomValidity` also replaces the `validationMessage`
+            call_method(&elem, "setCustomValidity", &JsValue::from_str(""));
+            js_sys::Reflect::set(
+                &elem,
+                &JsValue::from_str("validationMessage"),
+                &JsValue::from_str(validation_message.as_deref().unwrap_or_default()),
+            )
+            .unwrap();
+
+            let pending = Pending {
+                elem: elem.clone(),
+                state: Rc::clone(&state),
+                icon: validator.pending_icon.clone(),
+                icon_trailing: icon_trailing.clone(),
+                onpending: onpending.clone(),
+            };
+            let value = js_sys::Reflect::get(&elem, &JsValue::from_str("value"))
+                .ok()
+                .and_then(|value| value.as_string())
+                .unwrap_or_default();
+            if value.is_empty() {
+                pending.set(false);
+                return;
+            }
+            pending.set(true);
+
+            let check = Rc::clone(&validator.check);
+            *state.timeout.borrow_mut() = Some(Timeout::new(validator.debounce, move || {
+                let future = check(value);
+                wasm_bindgen_futures::spawn_local(async move {
+                    let result = future.await;
+                    if pending.state.generation.get() != generation {
+                        return;
+                    }
+                    pending.set(false);
+                    if let Err(message) = result {
+                        call_method(&pending.elem, "setCustomValidity", &message.into());
+                        call_method(&pending.elem, "reportValidity", &JsValue::UNDEFINED);
+                    }
+                });
+            }));
+        })
+    }
+}
+
+/// State of the async validation of one rendered field
+#[derive(Default)]
+pub(crate) struct AsyncValidation {
+    /// Incremented on every input, checks started for an older generation are
+    /// stale
+    generation: Cell<u64>,
+    timeout: RefCell<Option<Timeout>>,
+    pending: Cell<bool>,
+}
+
+struct Pending {
+    elem: Element,
+    state: Rc<AsyncValidation>,
+    icon: Option<String>,
+    icon_trailing: Option<String>,
+    onpending: Option<StaticCallback<bool>>,
+}
+
+impl Pending {
+    fn set(&self, pending: bool) {
+        if self.state.pending.replace(pending) == pending {
+            return;
+        }
+        if let Some(icon) = &self.icon {
+            let icon = match pending {
+                true => Some(icon),
+                false => self.icon_trailing.as_ref(),
+            };
+            js_sys::Reflect::set(
+                &self.elem,
+                &JsValue::from_str("iconTrailing"),
+                &icon.map_or(JsValue::UNDEFINED, |icon| JsValue::from_str(icon)),
+            )
+            .unwrap();
+        }
+        if let Some(onpending) = &self.onpending {
+            onpending.call(pending);
+        }
+    }
+}
+
+fn call_method(elem: &Element, name: &str, arg: &JsValue) {
+    if let Some(function) = js_sys::Reflect::get(elem, &JsValue::from_str(name))
+        .ok()
+        .and_then(|function| function.dyn_into::<js_sys::Function>().ok())
+    {
+        function.call1(elem, arg).ok();
+    }
+}
@@ -0,0 +1,156 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Error when parsing a [`Date`] or [`Time`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDateTimeError;
+
+impl fmt::Display for ParseDateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid date or time")
+    }
+}
+
+impl std::error::Error for ParseDateTimeError {}
+
+/// A calendar date, the value of a [`MatDateField`](crate::MatDateField)
+///
+/// Formatted and parsed as `yyyy-mm-dd`, like the value of an
+/// `<input type="date">`.
+///
+/// ```
+/// use material_dioxus::text_inputs::Date;
+///
+/// let date: Date = "2024-02-29".parse().unwrap();
+/// assert_eq!(date, Date::new(2024, 2, 29).unwrap());
+/// assert_eq!(date.to_string(), "2024-02-29");
+/// assert!("2023-02-29".parse::<Date>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a date, `None` if `month` or `day` are out of range.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        let days = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            _ => return None,
+        };
+        (1..=days)
+            .contains(&day)
+            .then_some(Self { year, month, day })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// The month from 1 to 12
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// The day of the month starting at 1
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = ParseDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // the year can have more than four digits
+        let (rest, day) = s.rsplit_once('-').ok_or(ParseDateTimeError)?;
+        let (year, month) = rest.rsplit_once('-').ok_or(ParseDateTimeError)?;
+        Date::new(
+            parse_digits(year, 4..=6)? as i32,
+            parse_digits(month, 2..=2)? as u8,
+            parse_digits(day, 2..=2)? as u8,
+        )
+        .ok_or(ParseDateTimeError)
+    }
+}
+
+/// A time of day, the value of a [`MatTimeField`](crate::MatTimeField)
+///
+/// Formatted and parsed as `hh:mm` or `hh:mm:ss`, like the value of an
+/// `<input type="time">`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl Time {
+    /// Creates a time, `None` if a component is out of range.
+    pub fn new(hour: u8, minute: u8, second: u8) -> Option<Self> {
+        (hour < 24 && minute < 60 && second < 60).then_some(Self {
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)?;
+        if self.second != 0 {
+            write!(f, ":{:02}", self.second)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Time {
+    type Err = ParseDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(':');
+        let hour = parse_digits(parts.next().ok_or(ParseDateTimeError)?, 2..=2)?;
+        let minute = parse_digits(parts.next().ok_or(ParseDateTimeError)?, 2..=2)?;
+        let second = match parts.next() {
+            // fractions of a second are dropped
+            Some(second) => parse_digits(second.split('.').next().unwrap(), 2..=2)?,
+            None => 0,
+        };
+        if parts.next().is_some() {
+            return Err(ParseDateTimeError);
+        }
+        Time::new(hour as u8, minute as u8, second as u8).ok_or(ParseDateTimeError)
+    }
+}
+
+fn parse_digits(s: &str, len: std::ops::RangeInclusive<usize>) -> Result<u32, ParseDateTimeError> {
+    if !len.contains(&s.len()) || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseDateTimeError);
+    }
+    s.parse().map_err(|_| ParseDateTimeError)
+}
@@ -0,0 +1,183 @@
+use std::rc::Rc;
+
+use wasm_bindgen::prelude::*;
+use web_sys::{Element, HtmlInputElement};
+
+/// Formats the value of a [`MatTextField`](crate::MatTextField) as the user
+/// types, passed to its `formatter` prop.
+///
+/// The value is reduced to its raw characters, the ones `accept`ed by the
+/// formatter, which are then formatted again. The caret is kept behind the
+/// same raw character.
+///
+/// ```
+/// use material_dioxus::text_inputs::Formatter;
+///
+/// let phone = Formatter::mask("(###) ###-####");
+/// assert_eq!(phone.format("1234567"), "(123) 456-7");
+/// assert_eq!(phone.raw("(123) 456-7"), "1234567");
+///
+/// let upper = Formatter::new(char::is_alphanumeric, |raw| raw.to_uppercase());
+/// assert_eq!(upper.format("ab-12"), "AB12");
+/// ```
+#[derive(Clone)]
+pub struct Formatter {
+    accept: Rc<dyn Fn(char) -> bool>,
+    format: Rc<dyn Fn(&str) -> String>,
+}
+
+/// The value of a formatted [`MatTextField`](crate::MatTextField)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormattedValue {
+    /// Only the accepted characters, e.g. the digits of a phone number
+    pub raw: String,
+    /// The value as shown in the text field
+    pub formatted: String,
+}
+
+impl Formatter {
+    /// Creates a formatter from a predicate for raw characters and a function
+    /// formatting the raw characters.
+    ///
+    /// `format` must keep the raw characters in order and may only insert
+    /// characters that are not accepted, otherwise the caret position is off.
+    pub fn new(
+        accept: impl Fn(char) -> bool + 'static,
+        format: impl Fn(&str) -> String + 'static,
+    ) -> Self {
+        Self {
+            accept: Rc::new(accept),
+            format: Rc::new(format),
+        }
+    }
+
+    /// Creates a formatter from a mask in which `#` stands for a digit, `A`
+    /// for a letter and `*` for a letter or digit. All other characters are
+    /// inserted as the user types, so they should not be accepted by any of
+    /// the placeholders. Input beyond the end of the mask is dropped.
+    pub fn mask(mask: &str) -> Self {
+        let mask: Vec<char> = mask.chars().collect();
+        let accept = {
+            let mask = mask.clone();
+            move |c: char| mask.iter().any(|&slot| placeholder_accepts(slot, c))
+        };
+        let format = move |raw: &str| {
+            let mut raw = raw.chars().peekable();
+            let mut formatted = String::new();
+            for &slot in &mask {
+                if raw.peek().is_none() {
+                    break;
+                }
+                if !is_placeholder(slot) {
+                    formatted.push(slot);
+                    continue;
+                }
+                // skip raw characters the placeholder does not accept
+                while raw.next_if(|&c| !placeholder_accepts(slot, c)).is_some() {}
+                match raw.next() {
+                    Some(c) => formatted.push(c),
+                    None => break,
+                }
+            }
+            formatted
+        };
+        Self::new(accept, format)
+    }
+
+    /// The accepted characters of `value`.
+    pub fn raw(&self, value: &str) -> String {
+        value.chars().filter(|&c| (self.accept)(c)).collect()
+    }
+
+    /// Formats the accepted characters of `value`.
+    pub fn format(&self, value: &str) -> String {
+        (self.format)(&self.raw(value))
+    }
+
+    /// Formats the value of the rendered `mwc-textfield` `target` in place and
+    /// moves the caret behind the same raw character as before.
+    pub(crate) fn apply(&self, target: &Element) -> FormattedValue {
+        let input = js_sys::Reflect::get(target, &JsValue::from_str("formElement"))
+            .ok()
+            .and_then(|input| input.dyn_into::<HtmlInputElement>().ok());
+        let value = match &input {
+            Some(input) => input.value(),
+            None => js_sys::Reflect::get(target, &JsValue::from_str("value"))
+                .ok()
+                .and_then(|value| value.as_string())
+                .unwrap_or_default(),
+        };
+        let raw = self.raw(&value);
+        let formatted = (self.format)(&raw);
+        let result = FormattedValue { raw, formatted };
+        if result.formatted == value {
+            return result;
+        }
+
+        // the number of raw characters before the caret stays the same
+        let caret = input
+            .as_ref()
+            .and_then(|input| input.selection_start().ok().flatten())
+            .map(|caret| self.accepted_before(&value, caret));
+        js_sys::Reflect::set(
+            target,
+            &JsValue::from_str("value"),
+            &JsValue::from_str(&result.formatted),
+        )
+        .unwrap();
+        if let Some(input) = input {
+            // set the value of the inner input right away, the text field only
+            // updates it in its next render, which would move the caret to the
+            // end
+            input.set_value(&result.formatted);
+            if let Some(caret) = caret {
+                let caret = self.position_after(&result.formatted, caret);
+                input.set_selection_range(caret, caret).ok();
+            }
+        }
+        result
+    }
+
+    /// Counts the accepted characters in `value` before the UTF-16 index
+    /// `caret`.
+    fn accepted_before(&self, value: &str, caret: u32) -> usize {
+        let mut index = 0;
+        value
+            .chars()
+            .take_while(|c| {
+                index += c.len_utf16() as u32;
+                index <= caret
+            })
+            .filter(|&c| (self.accept)(c))
+            .count()
+    }
+
+    /// The UTF-16 index in `value` right after `count` accepted characters.
+    fn position_after(&self, value: &str, count: usize) -> u32 {
+        let mut seen = 0;
+        let mut index = 0;
+        for c in value.chars() {
+            if seen == count {
+                break;
+            }
+            if (self.accept)(c) {
+                seen += 1;
+            }
+            index += c.len_utf16() as u32;
+        }
+        index
+    }
+}
+
+fn is_placeholder(slot: char) -> bool {
+    matches!(slot, '#' | 'A' | '*')
+}
+
+fn placeholder_accepts(slot: char, c: char) -> bool {
+    match slot {
+        '#' => c.is_ascii_digit(),
+        'A' => c.is_alphabetic(),
+        '*' => c.is_alphanumeric(),
+        _ => false,
+    }
+}
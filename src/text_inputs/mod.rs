@@ -1,43 +1,192 @@
+/// Implements the methods shared by [`TextFieldLink`] and `TextAreaLink`,
+/// which only differ in the element type `$elem`.
+#[cfg(any(feature = "textfield", feature = "textarea"))]
+macro_rules! text_input_link {
+    ($link:ident, $elem:ty, $name:literal, $package:literal) => {
+        impl $link {
+            #[doc = concat!(
+                "Binds to `checkValidity` method.\n\n",
+                "Returns whether the ", $name, " is valid without showing the validation\n",
+                "message. `None` if the ", $name, " is not mounted.\n\n",
+                "See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/",
+                $package, "#methods) for details",
+            )]
+            pub fn check_validity(&self) -> Option<bool> {
+                self.0.cast::<$elem>().map(|elem| elem.check_validity())
+            }
+
+            #[doc = concat!(
+                "Binds to `reportValidity` method.\n\n",
+                "Returns whether the ", $name, " is valid and shows the validation\n",
+                "message if it is not. `None` if the ", $name, " is not mounted.\n\n",
+                "See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/",
+                $package, "#methods) for details",
+            )]
+            pub fn report_validity(&self) -> Option<bool> {
+                self.0.cast::<$elem>().map(|elem| elem.report_validity())
+            }
+
+            #[doc = concat!(
+                "Binds to `setCustomValidity` method.\n\n",
+                "See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/",
+                $package, "#methods) for details",
+            )]
+            pub fn set_custom_validity(&self, message: &str) {
+                if let Some(elem) = self.0.cast::<$elem>() {
+                    elem.set_custom_validity(message);
+                }
+            }
+
+            #[doc = concat!(
+                "Binds to `layout` method.\n\n",
+                "See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/",
+                $package, "#methods) for details",
+            )]
+            pub fn layout(&self) {
+                if let Some(elem) = self.0.cast::<$elem>() {
+                    elem.layout();
+                }
+            }
+
+            #[doc = concat!(
+                "Binds to `focus` method.\n\n",
+                "See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/",
+                $package, "#methods) for details",
+            )]
+            pub fn focus(&self) {
+                if let Some(elem) = self.0.cast::<$elem>() {
+                    elem.focus();
+                }
+            }
+
+            #[doc = concat!(
+                "Binds to `blur` method.\n\n",
+                "See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/",
+                $package, "#methods) for details",
+            )]
+            pub fn blur(&self) {
+                if let Some(elem) = self.0.cast::<$elem>() {
+                    elem.blur();
+                }
+            }
+
+            #[doc = concat!(
+                "Binds to `select` method.\n\n",
+                "See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/",
+                $package, "#methods) for details",
+            )]
+            pub fn select(&self) {
+                if let Some(elem) = self.0.cast::<$elem>() {
+                    elem.select();
+                }
+            }
+
+            #[doc = concat!(
+                "Binds to `setSelectionRange` method.\n\n",
+                "`direction` is one of `\"forward\"`, `\"backward\"` or `\"none\"`.\n\n",
+                "See [here](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/",
+                $package, "#methods) for details",
+            )]
+            pub fn set_selection_range(&self, start: u32, end: u32, direction: Option<&str>) {
+                if let Some(elem) = self.0.cast::<$elem>() {
+                    elem.set_selection_range(start, end, direction.map(str::to_owned));
+                }
+            }
+        }
+    };
+}
+
 #[cfg(feature = "textfield")]
 mod textfield;
 #[cfg(feature = "textfield")]
 pub use textfield::*;
 
-#[cfg(any(feature = "textfield", feature = "textarea"))]
+#[cfg(any(
+    feature = "textfield",
+    feature = "textarea",
+    feature = "select",
+    feature = "form"
+))]
 pub(crate) mod validity_state;
-#[cfg(any(feature = "textfield", feature = "textarea"))]
+#[cfg(any(
+    feature = "textfield",
+    feature = "textarea",
+    feature = "select",
+    feature = "form"
+))]
 pub use validity_state::ValidityState;
 
+#[cfg(any(feature = "textfield", feature = "textarea", feature = "select"))]
+pub mod validators;
+
+#[cfg(any(feature = "textfield", feature = "textarea"))]
+mod async_validator;
+#[cfg(any(feature = "textfield", feature = "textarea"))]
+pub use async_validator::{AsyncValidator, ValidationFuture};
+
+#[cfg(any(feature = "textfield", feature = "textarea"))]
+mod formatter;
+#[cfg(any(feature = "textfield", feature = "textarea"))]
+pub use formatter::{FormattedValue, Formatter};
+
 #[cfg(any(feature = "textfield", feature = "textarea"))]
 pub(crate) mod text_field_type;
 #[cfg(any(feature = "textfield", feature = "textarea"))]
 pub use text_field_type::*;
 
+#[cfg(feature = "textfield")]
+mod step;
+#[cfg(feature = "textfield")]
+pub use step::Step;
+
+#[cfg(feature = "textfield")]
+mod date_time;
+#[cfg(feature = "textfield")]
+pub use date_time::{Date, ParseDateTimeError, Time};
+
+#[cfg(feature = "textfield")]
+mod typed_field;
+#[cfg(feature = "textfield")]
+pub use typed_field::*;
+
 #[cfg(feature = "textarea")]
 mod textarea;
 #[cfg(feature = "textarea")]
 pub use textarea::*;
 
-#[cfg(any(feature = "textfield", feature = "textarea"))]
+#[cfg(any(
+    feature = "textfield",
+    feature = "textarea",
+    feature = "select",
+    feature = "form"
+))]
 pub use web_sys::ValidityState as NativeValidityState;
 
+#[cfg(any(feature = "textfield", feature = "textarea", feature = "select"))]
 use std::rc::Rc;
 
-use gloo::events::EventListener;
-use wasm_bindgen::JsValue;
-use web_sys::Event;
-
-use crate::StaticCallback;
-
+#[cfg(any(feature = "textfield", feature = "textarea", feature = "select"))]
+use {
+    crate::StaticCallback,
+    gloo::events::EventListener,
+    std::cell::Cell,
+    wasm_bindgen::{JsCast, JsValue},
+};
 #[cfg(any(feature = "textfield", feature = "textarea"))]
+use {
+    gloo::events::{EventListenerOptions, EventListenerPhase},
+    web_sys::Event,
+};
+
+#[cfg(any(feature = "textfield", feature = "textarea", feature = "select"))]
 pub(crate) type ValidityTransformFn = dyn Fn(String, NativeValidityState) -> ValidityState;
 
-#[cfg(any(feature = "textfield", feature = "textarea"))]
+#[cfg(any(feature = "textfield", feature = "textarea", feature = "select"))]
 #[derive(Clone)]
 /// Owned function for validity props
 pub struct ValidityTransform(pub(crate) Rc<ValidityTransformFn>);
 
-#[cfg(any(feature = "textfield", feature = "textarea"))]
+#[cfg(any(feature = "textfield", feature = "textarea", feature = "select"))]
 impl ValidityTransform {
     pub fn new<F: Fn(String, NativeValidityState) -> ValidityState + 'static>(
         func: F,
@@ -46,21 +195,92 @@ impl ValidityTransform {
     }
 }
 
+#[cfg(any(feature = "textfield", feature = "textarea", feature = "select"))]
 impl PartialEq for ValidityTransform {
-    #[allow(clippy::vtable_address_comparisons)]
+    #[allow(ambiguous_wide_pointer_comparisons)]
     fn eq(&self, other: &Self) -> bool {
         Rc::ptr_eq(&self.0, &other.0)
     }
 }
 
+/// Creates the `input` listener for the `_oninput` prop.
+///
+/// With a `formatter`, the value is formatted before `callback` is called and
+/// the listener runs in the capture phase, so that other listeners on
+/// `target` already see the formatted value. `onformatted` receives both the
+/// raw and the formatted value.
+#[cfg(any(feature = "textfield", feature = "textarea"))]
 fn set_on_input_handler(
     target: &web_sys::Element,
-    callback: StaticCallback<String>,
+    callback: Option<StaticCallback<String>>,
+    formatter: Option<(Formatter, Option<StaticCallback<FormattedValue>>)>,
     convert: impl Fn((Event, JsValue)) -> String + 'static,
 ) -> EventListener {
-    EventListener::new(target, "input", move |event: &Event| {
-        let js_value = JsValue::from(event);
+    let options = EventListenerOptions {
+        phase: match formatter {
+            Some(_) => EventListenerPhase::Capture,
+            None => EventListenerPhase::Bubble,
+        },
+        passive: true,
+    };
+    let elem = target.clone();
+    EventListener::new_with_options(target, "input", options, move |event: &Event| {
+        if let Some((formatter, onformatted)) = &formatter {
+            let value = formatter.apply(&elem);
+            if let Some(onformatted) = onformatted {
+                onformatted.call(value);
+            }
+        }
+        if let Some(callback) = &callback {
+            let js_value = JsValue::from(event);
 
-        callback.call(convert((event.clone(), js_value)))
+            callback.call(convert((event.clone(), js_value)))
+        }
     })
 }
+
+/// Reads the `validity` property of a rendered `mwc-textfield`, `mwc-textarea`
+/// or `mwc-select`. This includes the result of a `validityTransform`.
+#[cfg(any(feature = "textfield", feature = "textarea", feature = "select"))]
+fn current_validity(target: &web_sys::Element) -> ValidityState {
+    js_sys::Reflect::get(target, &JsValue::from_str("validity"))
+        .unwrap()
+        .unchecked_into::<NativeValidityState>()
+        .into()
+}
+
+/// Creates the listeners for the `_oninvalid` and `_onvalidity_change` props.
+///
+/// The validity is checked again after each of `change_events` and `invalid`,
+/// `last_validity` holds the last reported state so that
+/// `onvalidity_change` is only called when it actually changed.
+#[cfg(any(feature = "textfield", feature = "textarea", feature = "select"))]
+pub(crate) fn set_validity_handlers(
+    target: &web_sys::Element,
+    change_events: &[&'static str],
+    oninvalid: Option<StaticCallback<ValidityState>>,
+    onvalidity_change: Option<StaticCallback<ValidityState>>,
+    last_validity: &Rc<Cell<Option<ValidityState>>>,
+) -> Vec<EventListener> {
+    let mut listeners = vec![];
+    if let Some(callback) = oninvalid {
+        let elem = target.clone();
+        listeners.push(EventListener::new(target, "invalid", move |_| {
+            callback.call(current_validity(&elem))
+        }));
+    }
+    if let Some(callback) = onvalidity_change {
+        for event in change_events.iter().copied().chain(["invalid"]) {
+            let elem = target.clone();
+            let callback = callback.clone();
+            let last_validity = Rc::clone(last_validity);
+            listeners.push(EventListener::new(target, event, move |_| {
+                let validity = current_validity(&elem);
+                if last_validity.replace(Some(validity)) != Some(validity) {
+                    callback.call(validity)
+                }
+            }));
+        }
+    }
+    listeners
+}
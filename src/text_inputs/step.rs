@@ -0,0 +1,44 @@
+use std::fmt;
+
+/// The `step` of a [`MatTextField`](crate::MatTextField)
+///
+/// Type: `number | string`, so integers, floats and strings can be converted
+/// into it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step(String);
+
+impl Step {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<i64> for Step {
+    fn from(step: i64) -> Self {
+        Step(step.to_string())
+    }
+}
+
+impl From<f64> for Step {
+    fn from(step: f64) -> Self {
+        Step(step.to_string())
+    }
+}
+
+impl From<String> for Step {
+    fn from(step: String) -> Self {
+        Step(step)
+    }
+}
+
+impl From<&str> for Step {
+    fn from(step: &str) -> Self {
+        Step(step.to_owned())
+    }
+}
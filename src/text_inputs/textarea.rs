@@ -1,8 +1,11 @@
+use std::cell::{Cell, RefCell};
 use std::fmt;
 use std::marker::PhantomData;
+use std::rc::Rc;
 
-use crate::text_inputs::set_on_input_handler;
 use crate::text_inputs::validity_state::ValidityStateJS;
+use crate::text_inputs::{set_on_input_handler, set_validity_handlers};
+use crate::utils::WeakComponentLink;
 use crate::StaticCallback;
 use dioxus::core::AttributeValue;
 use dioxus::prelude::*;
@@ -11,7 +14,11 @@ use wasm_bindgen::JsCast;
 use web_sys::Node;
 use web_sys::ValidityState as NativeValidityState;
 
+use super::async_validator::AsyncValidation;
+use super::validators::Validators;
+use super::AsyncValidator;
 use super::TextFieldType;
+use super::ValidityState;
 use super::ValidityTransform;
 
 #[wasm_bindgen(module = "/build/mwc-textarea.js")]
@@ -37,6 +44,35 @@ extern "C" {
 
     #[wasm_bindgen(method, setter)]
     fn set_value(this: &TextArea, val: &JsValue);
+
+    #[wasm_bindgen(method, js_name = checkValidity)]
+    fn check_validity(this: &TextArea) -> bool;
+
+    #[wasm_bindgen(method, js_name = reportValidity)]
+    fn report_validity(this: &TextArea) -> bool;
+
+    #[wasm_bindgen(method, js_name = setCustomValidity)]
+    fn set_custom_validity(this: &TextArea, message: &str);
+
+    #[wasm_bindgen(method)]
+    fn layout(this: &TextArea);
+
+    #[wasm_bindgen(method)]
+    fn focus(this: &TextArea);
+
+    #[wasm_bindgen(method)]
+    fn blur(this: &TextArea);
+
+    #[wasm_bindgen(method)]
+    fn select(this: &TextArea);
+
+    #[wasm_bindgen(method, js_name = setSelectionRange)]
+    fn set_selection_range(
+        this: &TextArea,
+        selection_start: u32,
+        selection_end: u32,
+        selection_direction: Option<String>,
+    );
 }
 
 loader_hack!(TextArea);
@@ -123,6 +159,19 @@ pub struct TextAreaProps<'a> {
     pub auto_validate: bool,
     #[props(default)]
     pub validity_transform: Option<ValidityTransform>,
+    /// Validation rules, checked after the native validation. The message of
+    /// the first failing rule replaces `validation_message`. Takes precedence
+    /// over `validity_transform`.
+    #[props(default)]
+    pub validators: Option<Validators>,
+    /// Validation waiting for a future, e.g. a server lookup, after the
+    /// native validation and `validators`. See [`AsyncValidator`].
+    #[props(default)]
+    pub async_validator: Option<AsyncValidator>,
+    /// Called with `true` when the check of `async_validator` is scheduled
+    /// and with `false` when it finished or is no longer needed.
+    #[props(into)]
+    pub _onpending: Option<StaticCallback<bool>>,
     #[props(default)]
     pub validate_on_initial_render: bool,
     #[props(into)]
@@ -132,6 +181,20 @@ pub struct TextAreaProps<'a> {
     _lifetime: Option<PhantomData<&'a ()>>,
     #[props(default)]
     pub name: Option<String>,
+    /// Binds to `invalid` event on `mwc-textarea`
+    ///
+    /// The argument passed to callback is the current validity, including
+    /// the result of `validity_transform`. Fired when a validity check, e.g.
+    /// [`TextAreaLink::report_validity`], finds the text area invalid.
+    #[props(into)]
+    pub _oninvalid: Option<StaticCallback<ValidityState>>,
+    /// Called with the new validity whenever it changes after an `input`,
+    /// `change` or `invalid` event.
+    #[props(into)]
+    pub _onvalidity_change: Option<StaticCallback<ValidityState>>,
+    /// [`TextAreaLink`] for calling methods of this text area
+    #[props(default)]
+    pub textarea_link: TextAreaLink,
 
     #[props(into, default)]
     pub style: String,
@@ -147,24 +210,69 @@ fn render<'a>(cx: Scope<'a, TextAreaProps<'a>>) -> Element<'a> {
     let id = crate::use_id(cx, "textarea");
     let input_listener = cx.use_hook(|| None);
     let validity_transform_closure = cx.use_hook(|| None);
+    // read by the `validators` closure, which is only created once
+    let validation_message = cx.use_hook(|| Rc::new(RefCell::new(None)));
+    let validity_listeners = cx.use_hook(Vec::new);
+    let last_validity = cx.use_hook(|| Rc::new(Cell::new(None)));
+    let async_validation = cx.use_hook(|| Rc::new(AsyncValidation::default()));
+    let async_listener = cx.use_hook(|| None);
+    #[cfg(feature = "form")]
+    let form_field = crate::form::field::use_form_field(cx, id, cx.props.name.as_deref(), || {
+        Some(cx.props.value.clone().unwrap_or_default().into())
+    });
+    cx.props.textarea_link.0.set_id(id);
+    validation_message.replace(cx.props.validation_message.clone());
     if let Some(elem) = crate::get_elem_by_id(id) {
         let target = elem.clone();
         let textarea = JsValue::from(elem).dyn_into::<TextArea>().unwrap();
         textarea.set_type(&JsValue::from(cx.props.field_type.as_str()));
+        #[cfg(feature = "form")]
+        let form_value = form_field.map(|field| {
+            field.bind(&target, &["input", "change"], crate::form::field::read_text);
+            field.value().to_string()
+        });
+        #[cfg(not(feature = "form"))]
+        let form_value = None::<String>;
         textarea.set_value(&JsValue::from_str(
-            cx.props
-                .value
-                .as_ref()
-                .map(|s| s.as_ref())
+            form_value
+                .as_deref()
+                .or(cx.props.value.as_deref())
                 .unwrap_or_default(),
         ));
         if let Some(listener) = cx.props._oninput.clone() {
-            *input_listener = Some(set_on_input_handler(&target, listener, |(_, detail)| {
-                detail
-                    .unchecked_into::<MatTextAreaInputEvent>()
-                    .target()
-                    .value()
-            }));
+            *input_listener = Some(set_on_input_handler(
+                &target,
+                Some(listener),
+                None,
+                |(_, detail)| {
+                    detail
+                        .unchecked_into::<MatTextAreaInputEvent>()
+                        .target()
+                        .value()
+                },
+            ));
+        }
+        if let Some(validator) = &cx.props.async_validator {
+            *async_listener = Some(validator.listen(
+                &target,
+                async_validation,
+                cx.props.icon_trailing.clone(),
+                cx.props.validation_message.clone(),
+                cx.props._onpending.clone(),
+            ));
+        }
+        *validity_listeners = set_validity_handlers(
+            &target,
+            &["input", "change"],
+            cx.props._oninvalid.clone(),
+            cx.props._onvalidity_change.clone(),
+            last_validity,
+        );
+        if let (Some(validators), None) = (cx.props.validators.clone(), &validity_transform_closure)
+        {
+            *validity_transform_closure =
+                Some(validators.closure(target.clone(), Rc::clone(validation_message)));
+            textarea.set_validity_transform(validity_transform_closure.as_ref().unwrap());
         }
         if let (Some(transform), None) = (
             cx.props.validity_transform.clone(),
@@ -216,6 +324,17 @@ fn render<'a>(cx: Scope<'a, TextAreaProps<'a>>) -> Element<'a> {
 
 component!('a, MatTextArea, TextAreaProps, render, TextArea, "textarea");
 
+/// Link to a rendered [`MatTextArea`], passed to it via
+/// [`TextAreaProps::textarea_link`].
+///
+/// The link should be created once per component, e.g. with
+/// `cx.use_hook(TextAreaLink::default)`. Calling methods while the text area
+/// is not mounted has no effect.
+#[derive(Clone, Default, PartialEq)]
+pub struct TextAreaLink(WeakComponentLink);
+
+text_input_link!(TextAreaLink, TextArea, "text area", "textarea");
+
 #[wasm_bindgen]
 extern "C" {
     type MatTextAreaInputEvent;
@@ -1,7 +1,14 @@
+use std::cell::{Cell, RefCell};
 use std::marker::PhantomData;
+use std::rc::Rc;
 
-use super::set_on_input_handler;
-use crate::text_inputs::{validity_state::ValidityStateJS, TextFieldType, ValidityTransform};
+use super::{set_on_input_handler, set_validity_handlers};
+use crate::text_inputs::{
+    async_validator::AsyncValidation, validators::Validators, validity_state::ValidityStateJS,
+    AsyncValidator, FormattedValue, Formatter, Step, TextFieldType, ValidityState,
+    ValidityTransform,
+};
+use crate::utils::WeakComponentLink;
 use crate::StaticCallback;
 use dioxus::core::AttributeValue;
 use dioxus::prelude::*;
@@ -34,6 +41,35 @@ extern "C" {
 
     #[wasm_bindgen(method, setter)]
     fn set_value(this: &TextField, val: &JsValue);
+
+    #[wasm_bindgen(method, js_name = checkValidity)]
+    fn check_validity(this: &TextField) -> bool;
+
+    #[wasm_bindgen(method, js_name = reportValidity)]
+    fn report_validity(this: &TextField) -> bool;
+
+    #[wasm_bindgen(method, js_name = setCustomValidity)]
+    fn set_custom_validity(this: &TextField, message: &str);
+
+    #[wasm_bindgen(method)]
+    fn layout(this: &TextField);
+
+    #[wasm_bindgen(method)]
+    fn focus(this: &TextField);
+
+    #[wasm_bindgen(method)]
+    fn blur(this: &TextField);
+
+    #[wasm_bindgen(method)]
+    fn select(this: &TextField);
+
+    #[wasm_bindgen(method, js_name = setSelectionRange)]
+    fn set_selection_range(
+        this: &TextField,
+        selection_start: u32,
+        selection_end: u32,
+        selection_direction: Option<String>,
+    );
 }
 
 loader_hack!(TextField);
@@ -69,6 +105,11 @@ pub struct TextFieldProps<'a> {
     pub char_counter: bool,
     #[props(default)]
     pub outlined: bool,
+    /// Overrides [`Shape::small`](crate::theming::Shape::small) of `MatTheme`
+    /// for the outline of outlined text fields and the top corners of filled
+    /// ones, e.g. `12px`
+    #[props(into)]
+    pub corner_radius: Option<String>,
     #[props(into)]
     pub helper: Option<String>,
     #[props(default)]
@@ -91,17 +132,40 @@ pub struct TextFieldProps<'a> {
     #[props(default)]
     pub size: Option<i64>,
     // ...step size
-    #[props(default)]
-    pub step: Option<i64>,
+    /// Integers, floats and strings are converted into a [`Step`], so
+    /// `step: 5` keeps working.
+    #[props(into)]
+    pub step: Option<Step>,
     #[props(default)]
     pub auto_validate: bool,
     pub validity_transform: Option<ValidityTransform>,
+    /// Validation rules, checked after the native validation. The message of
+    /// the first failing rule replaces `validation_message`. Takes precedence
+    /// over `validity_transform`.
+    #[props(default)]
+    pub validators: Option<Validators>,
+    /// Validation waiting for a future, e.g. a server lookup, after the
+    /// native validation and `validators`. See [`AsyncValidator`].
+    #[props(default)]
+    pub async_validator: Option<AsyncValidator>,
+    /// Called with `true` when the check of `async_validator` is scheduled
+    /// and with `false` when it finished or is no longer needed.
+    #[props(into)]
+    pub _onpending: Option<StaticCallback<bool>>,
     #[props(default)]
     pub validate_on_initial_render: bool,
     #[props(into)]
     // the name cannot start with `on` or dioxus will expect an `EventHandler` which aren't static
     // and thus cannot be used here
     pub _oninput: Option<StaticCallback<String>>,
+    /// Formats the value as the user types, e.g. with a mask for phone
+    /// numbers. `_oninput` then receives the formatted value.
+    #[props(default)]
+    pub formatter: Option<Formatter>,
+    /// Called with the raw and the formatted value on every `input` if a
+    /// `formatter` is set.
+    #[props(into)]
+    pub _onformatted_input: Option<StaticCallback<FormattedValue>>,
     _lifetime: Option<PhantomData<&'a ()>>,
     #[props(into)]
     pub name: Option<String>,
@@ -110,6 +174,20 @@ pub struct TextFieldProps<'a> {
     pub webkit_date_picker: bool,
     #[props(into)]
     pub _onchange: Option<StaticCallback<String>>,
+    /// Binds to `invalid` event on `mwc-textfield`
+    ///
+    /// The argument passed to callback is the current validity, including
+    /// the result of `validity_transform`. Fired when a validity check, e.g.
+    /// [`TextFieldLink::report_validity`], finds the text field invalid.
+    #[props(into)]
+    pub _oninvalid: Option<StaticCallback<ValidityState>>,
+    /// Called with the new validity whenever it changes after an `input`,
+    /// `change` or `invalid` event.
+    #[props(into)]
+    pub _onvalidity_change: Option<StaticCallback<ValidityState>>,
+    /// [`TextFieldLink`] for calling methods of this text field
+    #[props(default)]
+    pub textfield_link: TextFieldLink,
 
     #[props(into, default)]
     pub style: String,
@@ -126,24 +204,52 @@ fn render<'a>(cx: Scope<'a, TextFieldProps<'a>>) -> Element<'a> {
     let input_listener = cx.use_hook(|| None);
     let change_listener = cx.use_hook(|| None);
     let validity_transform_closure = cx.use_hook(|| None);
+    // read by the `validators` closure, which is only created once
+    let validation_message = cx.use_hook(|| Rc::new(RefCell::new(None)));
+    let validity_listeners = cx.use_hook(Vec::new);
+    let last_validity = cx.use_hook(|| Rc::new(Cell::new(None)));
+    let async_validation = cx.use_hook(|| Rc::new(AsyncValidation::default()));
+    let async_listener = cx.use_hook(|| None);
+    #[cfg(feature = "form")]
+    let form_field = crate::form::field::use_form_field(cx, id, cx.props.name.as_deref(), || {
+        Some(cx.props.value.clone().unwrap_or_default().into())
+    });
+    cx.props.textfield_link.0.set_id(id);
+    validation_message.replace(cx.props.validation_message.clone());
     if let Some(elem) = crate::get_elem_by_id(id) {
         let target = elem.clone();
         let textfield = JsValue::from(elem).dyn_into::<TextField>().unwrap();
         textfield.set_type(&JsValue::from(cx.props.field_type.as_str()));
+        #[cfg(feature = "form")]
+        let form_value = form_field.map(|field| {
+            field.bind(&target, &["input", "change"], crate::form::field::read_text);
+            field.value().to_string()
+        });
+        #[cfg(not(feature = "form"))]
+        let form_value = None::<String>;
         textfield.set_value(&JsValue::from_str(
-            cx.props
-                .value
-                .as_ref()
-                .map(|s| s.as_ref())
+            form_value
+                .as_deref()
+                .or(cx.props.value.as_deref())
                 .unwrap_or_default(),
         ));
-        if let Some(listener) = cx.props._oninput.clone() {
-            *input_listener = Some(set_on_input_handler(&target, listener, |(_, detail)| {
-                detail
-                    .unchecked_into::<MatTextFieldInputEvent>()
-                    .target()
-                    .value()
-            }));
+        let formatter = cx
+            .props
+            .formatter
+            .clone()
+            .map(|formatter| (formatter, cx.props._onformatted_input.clone()));
+        if cx.props._oninput.is_some() || formatter.is_some() {
+            *input_listener = Some(set_on_input_handler(
+                &target,
+                cx.props._oninput.clone(),
+                formatter,
+                |(_, detail)| {
+                    detail
+                        .unchecked_into::<MatTextFieldInputEvent>()
+                        .target()
+                        .value()
+                },
+            ));
         }
         if let Some(listener) = cx.props._onchange.clone() {
             to_owned![textfield];
@@ -151,6 +257,28 @@ fn render<'a>(cx: Scope<'a, TextFieldProps<'a>>) -> Element<'a> {
                 listener.call(textfield.value())
             }));
         }
+        if let Some(validator) = &cx.props.async_validator {
+            *async_listener = Some(validator.listen(
+                &target,
+                async_validation,
+                cx.props.icon_trailing.clone(),
+                cx.props.validation_message.clone(),
+                cx.props._onpending.clone(),
+            ));
+        }
+        *validity_listeners = set_validity_handlers(
+            &target,
+            &["input", "change"],
+            cx.props._oninvalid.clone(),
+            cx.props._onvalidity_change.clone(),
+            last_validity,
+        );
+        if let (Some(validators), None) = (cx.props.validators.clone(), &validity_transform_closure)
+        {
+            *validity_transform_closure =
+                Some(validators.closure(target.clone(), Rc::clone(validation_message)));
+            textfield.set_validity_transform(validity_transform_closure.as_ref().unwrap());
+        }
         if let (Some(transform), None) = (
             cx.props.validity_transform.clone(),
             &validity_transform_closure,
@@ -164,6 +292,13 @@ fn render<'a>(cx: Scope<'a, TextFieldProps<'a>>) -> Element<'a> {
             textfield.set_validity_transform(validity_transform_closure.as_ref().unwrap());
         }
     }
+    let shape = cx
+        .props
+        .corner_radius
+        .as_ref()
+        .filter(|radius| !radius.is_empty())
+        .map(|radius| format!("--mdc-shape-small: {radius}; "))
+        .unwrap_or_default();
     render! {
         mwc-textfield {
             id: id,
@@ -187,14 +322,14 @@ fn render<'a>(cx: Scope<'a, TextFieldProps<'a>>) -> Element<'a> {
             min: optional_string_attr!(cx.props.min),
             max: optional_string_attr!(cx.props.max),
             size: cx.props.size.map(|v| format_args!("{v}").into_value(cx.bump())).unwrap_or(AttributeValue::None),
-            step: cx.props.step.map(|v| format_args!("{v}").into_value(cx.bump())).unwrap_or(AttributeValue::None),
+            step: cx.props.step.as_ref().map(|v| v.as_str()).filter(|v| !v.is_empty()).map(AttributeValue::Text).unwrap_or(AttributeValue::None),
             autoValidate: bool_attr!(cx.props.auto_validate),
             validateOnInitialRender: bool_attr!(cx.props.validate_on_initial_render),
             name: optional_string_attr!(cx.props.name),
             dialogInitialFocus: bool_attr!(cx.props.dialog_initial_focus),
             webkitDatePicker: bool_attr!(cx.props.webkit_date_picker),
 
-            style: string_attr!(cx.props.style),
+            style: "{shape}{cx.props.style}",
             class: string_attr!(cx.props.class),
             slot: optional_string_attr!(cx.props.slot),
             dialogInitialFocus: bool_attr!(cx.props.dialog_initial_focus),
@@ -204,6 +339,17 @@ fn render<'a>(cx: Scope<'a, TextFieldProps<'a>>) -> Element<'a> {
 
 component!('a, MatTextField, TextFieldProps, render, TextField, "textfield");
 
+/// Link to a rendered [`MatTextField`], passed to it via
+/// [`TextFieldProps::textfield_link`].
+///
+/// The link should be created once per component, e.g. with
+/// `cx.use_hook(TextFieldLink::default)`. Calling methods while the text field
+/// is not mounted has no effect.
+#[derive(Clone, Default, PartialEq)]
+pub struct TextFieldLink(WeakComponentLink);
+
+text_input_link!(TextFieldLink, TextField, "text field", "textfield");
+
 #[wasm_bindgen]
 extern "C" {
     type MatTextFieldInputEvent;
@@ -211,3 +357,23 @@ extern "C" {
     #[wasm_bindgen(method, getter)]
     fn target(this: &MatTextFieldInputEvent) -> TextField;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_accepts_integers() {
+        let props = TextFieldProps::builder().step(5).build();
+        assert_eq!(props.step, Some(Step::from(5)));
+        assert_eq!(props.step.unwrap().as_str(), "5");
+    }
+
+    #[test]
+    fn step_accepts_floats_and_strings() {
+        let props = TextFieldProps::builder().step(0.5).build();
+        assert_eq!(props.step.unwrap().as_str(), "0.5");
+        let props = TextFieldProps::builder().step("any").build();
+        assert_eq!(props.step.unwrap().as_str(), "any");
+    }
+}
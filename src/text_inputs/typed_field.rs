@@ -0,0 +1,202 @@
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use dioxus::prelude::*;
+
+use super::validators::Validators;
+use super::{
+    AsyncValidator, Date, FormattedValue, Formatter, Step, TextFieldLink, TextFieldProps,
+    TextFieldType, Time, ValidityState,
+};
+use crate::{MatTextField, StaticCallback};
+
+/// Props for [`MatNumberField`], [`MatDateField`] and [`MatTimeField`]
+///
+/// `T` is the type of the value, `S` the type of the `step`. Only the typed
+/// props differ from [`TextFieldProps`](super::TextFieldProps).
+#[derive(Props)]
+pub struct TypedFieldProps<'a, T: 'static, S: 'static = T> {
+    pub value: Option<T>,
+    pub min: Option<T>,
+    pub max: Option<T>,
+    pub step: Option<S>,
+    #[props(into)]
+    pub label: Option<String>,
+    #[props(into)]
+    pub placeholder: Option<String>,
+    #[props(into)]
+    pub prefix: Option<String>,
+    #[props(into)]
+    pub suffix: Option<String>,
+    #[props(into)]
+    pub icon: Option<String>,
+    #[props(into)]
+    pub icon_trailing: Option<String>,
+    #[props(default)]
+    pub disabled: bool,
+    #[props(default)]
+    pub outlined: bool,
+    #[props(into)]
+    pub corner_radius: Option<String>,
+    #[props(into)]
+    pub helper: Option<String>,
+    #[props(default)]
+    pub helper_persistent: bool,
+    #[props(default)]
+    pub required: bool,
+    #[props(into)]
+    pub validation_message: Option<String>,
+    #[props(default)]
+    pub auto_validate: bool,
+    /// Validation rules, checked after the native validation and the check
+    /// whether the value can be parsed into `T`. The message of the first
+    /// failing rule replaces `validation_message`.
+    #[props(default)]
+    pub validators: Option<Validators>,
+    /// Validation waiting for a future, e.g. a server lookup, after the
+    /// native validation and `validators`. See [`AsyncValidator`].
+    #[props(default)]
+    pub async_validator: Option<AsyncValidator>,
+    /// Called with `true` when the check of `async_validator` is scheduled
+    /// and with `false` when it finished or is no longer needed.
+    #[props(into)]
+    pub _onpending: Option<StaticCallback<bool>>,
+    #[props(default)]
+    pub validate_on_initial_render: bool,
+    #[props(into)]
+    pub name: Option<String>,
+    /// Called with the parsed value on every `input`. `None` if the field is
+    /// empty or the value cannot be parsed.
+    #[props(into)]
+    // the name cannot start with `on` or dioxus will expect an `EventHandler` which aren't static
+    // and thus cannot be used here
+    pub _oninput: Option<StaticCallback<Option<T>>>,
+    /// Formats the value as the user types. `_oninput` then receives the
+    /// parsed formatted value.
+    #[props(default)]
+    pub formatter: Option<Formatter>,
+    /// Called with the raw and the formatted value on every `input` if a
+    /// `formatter` is set.
+    #[props(into)]
+    pub _onformatted_input: Option<StaticCallback<FormattedValue>>,
+    /// Like `_oninput`, but called on `change`.
+    #[props(into)]
+    pub _onchange: Option<StaticCallback<Option<T>>>,
+    /// Binds to `invalid` event on `mwc-textfield`
+    #[props(into)]
+    pub _oninvalid: Option<StaticCallback<ValidityState>>,
+    /// Called with the new validity whenever it changes after an `input`,
+    /// `change` or `invalid` event.
+    #[props(into)]
+    pub _onvalidity_change: Option<StaticCallback<ValidityState>>,
+    /// [`TextFieldLink`] for calling methods of this text field
+    #[props(default)]
+    pub textfield_link: TextFieldLink,
+    _lifetime: Option<PhantomData<&'a ()>>,
+
+    #[props(into, default)]
+    pub style: String,
+    #[props(into, default)]
+    pub class: String,
+    #[props(into)]
+    pub slot: Option<String>,
+    #[props(default)]
+    pub dialog_initial_focus: bool,
+}
+
+/// Props for [`MatNumberField`]
+pub type NumberFieldProps<'a, T> = TypedFieldProps<'a, T, T>;
+/// Props for [`MatDateField`], the `step` is in days
+pub type DateFieldProps<'a> = TypedFieldProps<'a, Date, u32>;
+/// Props for [`MatTimeField`], the `step` is in seconds
+pub type TimeFieldProps<'a> = TypedFieldProps<'a, Time, u32>;
+
+fn render<'a, T, S>(
+    cx: Scope<'a, TypedFieldProps<'a, T, S>>,
+    field_type: TextFieldType,
+) -> Element<'a>
+where
+    T: FromStr + Display + 'static,
+    S: Display + 'static,
+{
+    // values the browser accepts but `T` cannot represent are bad input, e.g.
+    // decimals for integers
+    let validators = cx.props.validators.clone().unwrap_or_default().bad_input(
+        |value| value.parse::<T>().is_ok(),
+        cx.props.validation_message.clone().unwrap_or_default(),
+    );
+    let to_string = |value: &Option<T>| value.as_ref().map(T::to_string);
+    let oninput = cx.props._oninput.clone();
+    let onchange = cx.props._onchange.clone();
+
+    render! {
+        MatTextField {
+            // the optional props are set directly to pass `None` through
+            ..TextFieldProps::builder().build(),
+            field_type: field_type,
+            value: to_string(&cx.props.value),
+            min: to_string(&cx.props.min),
+            max: to_string(&cx.props.max),
+            step: cx.props.step.as_ref().map(|step| Step::from(step.to_string())),
+            label: cx.props.label.clone(),
+            placeholder: cx.props.placeholder.clone(),
+            prefix: cx.props.prefix.clone(),
+            suffix: cx.props.suffix.clone(),
+            icon: cx.props.icon.clone(),
+            icon_trailing: cx.props.icon_trailing.clone(),
+            disabled: cx.props.disabled,
+            outlined: cx.props.outlined,
+            corner_radius: cx.props.corner_radius.clone(),
+            helper: cx.props.helper.clone(),
+            helper_persistent: cx.props.helper_persistent,
+            required: cx.props.required,
+            validation_message: cx.props.validation_message.clone(),
+            auto_validate: cx.props.auto_validate,
+            validate_on_initial_render: cx.props.validate_on_initial_render,
+            validators: Some(validators),
+            async_validator: cx.props.async_validator.clone(),
+            _onpending: cx.props._onpending.clone(),
+            name: cx.props.name.clone(),
+            _oninput: oninput.map(|oninput| {
+                StaticCallback::from(move |value: String| oninput.call(value.parse().ok()))
+            }),
+            formatter: cx.props.formatter.clone(),
+            _onformatted_input: cx.props._onformatted_input.clone(),
+            _onchange: onchange.map(|onchange| {
+                StaticCallback::from(move |value: String| onchange.call(value.parse().ok()))
+            }),
+            _oninvalid: cx.props._oninvalid.clone(),
+            _onvalidity_change: cx.props._onvalidity_change.clone(),
+            textfield_link: cx.props.textfield_link.clone(),
+
+            style: cx.props.style.clone(),
+            class: cx.props.class.clone(),
+            slot: cx.props.slot.clone(),
+            dialog_initial_focus: cx.props.dialog_initial_focus,
+        }
+    }
+}
+
+/// A [`MatTextField`] of type `number` with a typed value
+///
+/// Input that cannot be parsed into `T` sets `badInput`.
+#[allow(non_snake_case)]
+pub fn MatNumberField<'a, T>(cx: Scope<'a, NumberFieldProps<'a, T>>) -> Element<'a>
+where
+    T: FromStr + Display + 'static,
+{
+    render(cx, TextFieldType::Number)
+}
+
+/// A [`MatTextField`] of type `date` with a [`Date`] value
+#[allow(non_snake_case)]
+pub fn MatDateField<'a>(cx: Scope<'a, DateFieldProps<'a>>) -> Element<'a> {
+    render(cx, TextFieldType::Date)
+}
+
+/// A [`MatTextField`] of type `time` with a [`Time`] value
+#[allow(non_snake_case)]
+pub fn MatTimeField<'a>(cx: Scope<'a, TimeFieldProps<'a>>) -> Element<'a> {
+    render(cx, TextFieldType::Time)
+}
@@ -0,0 +1,287 @@
+//! Composable validation rules for text inputs.
+//!
+//! A [`Validators`] list is passed to the `validators` prop of
+//! [`MatTextField`](crate::MatTextField), [`MatTextArea`](crate::MatTextArea)
+//! or [`MatSelect`](crate::MatSelect). The rules are checked in order after the
+//! browser's native validation. The message of the first failing rule is shown
+//! as the field's validation message.
+//!
+//! ```no_run
+//! use material_dioxus::text_inputs::validators::Validators;
+//!
+//! let username = Validators::new()
+//!     .required("Please enter a username")
+//!     .min_length(3, "Must be at least 3 characters long")
+//!     .pattern("[a-z0-9_]*", "Only lowercase letters, digits and `_` are allowed");
+//! ```
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use js_sys::RegExp;
+use wasm_bindgen::prelude::*;
+
+use super::validity_state::ValidityStateJS;
+use super::{NativeValidityState, ValidityState, ValidityTransform};
+
+#[wasm_bindgen]
+extern "C" {
+    /// `RegExp(pattern, flags)`, which throws a `SyntaxError` for invalid
+    /// patterns
+    #[wasm_bindgen(catch, js_name = RegExp)]
+    fn try_reg_exp(pattern: &str, flags: &str) -> Result<RegExp, JsValue>;
+}
+
+/// The same expression browsers use for `<input type="email">`
+const EMAIL_PATTERN: &str = r"^[a-zA-Z0-9.!#$%&'*+/=?^_`{|}~-]+@[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)*$";
+
+#[derive(Clone)]
+enum RuleKind {
+    Required,
+    MinLength(usize),
+    MaxLength(usize),
+    /// The compiled pattern, or the error if it is invalid
+    Pattern(Result<RegExp, String>),
+    Email(RegExp),
+    Url,
+    MinValue(f64),
+    MaxValue(f64),
+    Custom(Rc<dyn Fn(&str) -> bool>),
+    /// Whether the value can be parsed by a typed field
+    #[cfg(feature = "textfield")]
+    BadInput(Rc<dyn Fn(&str) -> bool>),
+}
+
+#[derive(Clone)]
+struct Rule {
+    kind: RuleKind,
+    message: String,
+}
+
+impl Rule {
+    /// Checks `value` against this rule and sets the matching flag of `state`
+    /// on failure. Returns whether the value passed.
+    fn check(&self, value: &str, state: &mut ValidityState) -> bool {
+        // like native validation, only `required` applies to empty values
+        if value.is_empty() && !matches!(self.kind, RuleKind::Required) {
+            return true;
+        }
+        let length = value.chars().count();
+        let number = || value.trim().parse::<f64>().ok();
+        let (passed, flag): (bool, fn(&mut ValidityState, bool) -> &mut ValidityState) =
+            match &self.kind {
+                RuleKind::Required => (!value.is_empty(), ValidityState::set_value_missing),
+                RuleKind::MinLength(min) => (length >= *min, ValidityState::set_too_short),
+                RuleKind::MaxLength(max) => (length <= *max, ValidityState::set_too_long),
+                RuleKind::Pattern(pattern) => (
+                    pattern.as_ref().is_ok_and(|pattern| pattern.test(value)),
+                    ValidityState::set_pattern_mismatch,
+                ),
+                RuleKind::Email(reg_exp) => (reg_exp.test(value), ValidityState::set_type_mismatch),
+                RuleKind::Url => (
+                    web_sys::Url::new(value).is_ok(),
+                    ValidityState::set_type_mismatch,
+                ),
+                RuleKind::MinValue(min) => match number() {
+                    Some(num) => (num >= *min, ValidityState::set_range_underflow),
+                    None => (false, ValidityState::set_bad_input),
+                },
+                RuleKind::MaxValue(max) => match number() {
+                    Some(num) => (num <= *max, ValidityState::set_range_overflow),
+                    None => (false, ValidityState::set_bad_input),
+                },
+                RuleKind::Custom(predicate) => (predicate(value), ValidityState::set_custom_error),
+                #[cfg(feature = "textfield")]
+                RuleKind::BadInput(parses) => (parses(value), ValidityState::set_bad_input),
+            };
+        if !passed {
+            flag(state, true).set_valid(false);
+        }
+        passed
+    }
+}
+
+/// An ordered list of validation rules.
+///
+/// Every rule is added with the message that is shown when it fails. Rules
+/// other than [`required`](Self::required) are skipped for empty values.
+#[derive(Clone, Default)]
+pub struct Validators {
+    rules: Vec<Rule>,
+}
+
+impl Validators {
+    /// Creates an empty list of rules, which accepts every value.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn rule(mut self, kind: RuleKind, message: impl Into<String>) -> Self {
+        self.rules.push(Rule {
+            kind,
+            message: message.into(),
+        });
+        self
+    }
+
+    /// The value must not be empty. Sets `valueMissing` on failure.
+    pub fn required(self, message: impl Into<String>) -> Self {
+        self.rule(RuleKind::Required, message)
+    }
+
+    /// The value must be at least `min` characters long. Sets `tooShort` on
+    /// failure.
+    pub fn min_length(self, min: usize, message: impl Into<String>) -> Self {
+        self.rule(RuleKind::MinLength(min), message)
+    }
+
+    /// The value must be at most `max` characters long. Sets `tooLong` on
+    /// failure.
+    pub fn max_length(self, max: usize, message: impl Into<String>) -> Self {
+        self.rule(RuleKind::MaxLength(max), message)
+    }
+
+    /// The whole value must match the JavaScript regular expression `pattern`,
+    /// just like the `pattern` attribute of an `<input>`. Sets
+    /// `patternMismatch` on failure.
+    ///
+    /// The pattern is compiled once here. An invalid pattern is logged and
+    /// fails closed: every non-empty value is rejected with the message
+    /// ``Invalid pattern `<pattern>`: <error>`` instead of `message`.
+    pub fn pattern(self, pattern: impl Into<String>, message: impl Into<String>) -> Self {
+        let pattern = pattern.into();
+        match try_reg_exp(&format!("^(?:{pattern})$"), "u") {
+            Ok(reg_exp) => self.rule(RuleKind::Pattern(Ok(reg_exp)), message),
+            Err(err) => {
+                let error = err
+                    .dyn_ref::<js_sys::Error>()
+                    .map(|err| String::from(err.message()))
+                    .unwrap_or_else(|| format!("{err:?}"));
+                let message = format!("Invalid pattern `{pattern}`: {error}");
+                gloo::console::error!(&message);
+                self.rule(RuleKind::Pattern(Err(error)), message)
+            }
+        }
+    }
+
+    /// The value must be an email address. Sets `typeMismatch` on failure.
+    pub fn email(self, message: impl Into<String>) -> Self {
+        self.rule(RuleKind::Email(RegExp::new(EMAIL_PATTERN, "")), message)
+    }
+
+    /// The value must be an absolute URL. Sets `typeMismatch` on failure.
+    pub fn url(self, message: impl Into<String>) -> Self {
+        self.rule(RuleKind::Url, message)
+    }
+
+    /// The value must be a number greater than or equal to `min`. Sets
+    /// `rangeUnderflow`, or `badInput` if the value is not a number, on
+    /// failure.
+    pub fn min_value(self, min: f64, message: impl Into<String>) -> Self {
+        self.rule(RuleKind::MinValue(min), message)
+    }
+
+    /// The value must be a number less than or equal to `max`. Sets
+    /// `rangeOverflow`, or `badInput` if the value is not a number, on
+    /// failure.
+    pub fn max_value(self, max: f64, message: impl Into<String>) -> Self {
+        self.rule(RuleKind::MaxValue(max), message)
+    }
+
+    /// `predicate` must return `true` for the value. Sets `customError` on
+    /// failure.
+    pub fn custom(
+        self,
+        predicate: impl Fn(&str) -> bool + 'static,
+        message: impl Into<String>,
+    ) -> Self {
+        self.rule(RuleKind::Custom(Rc::new(predicate)), message)
+    }
+
+    /// Adds a rule before all others which sets `badInput` if `parses` returns
+    /// `false`.
+    #[cfg(feature = "textfield")]
+    pub(crate) fn bad_input(
+        mut self,
+        parses: impl Fn(&str) -> bool + 'static,
+        message: impl Into<String>,
+    ) -> Self {
+        self.rules.insert(
+            0,
+            Rule {
+                kind: RuleKind::BadInput(Rc::new(parses)),
+                message: message.into(),
+            },
+        );
+        self
+    }
+
+    /// Checks `value` against all rules, returning the message of the first
+    /// failing rule.
+    pub fn validate(&self, value: &str) -> Result<(), &str> {
+        let mut state = ValidityState::new();
+        match self
+            .rules
+            .iter()
+            .find(|rule| !rule.check(value, &mut state))
+        {
+            Some(rule) => Err(&rule.message),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks `value` against all rules, starting with the `native` state.
+    /// Returns the resulting state and the message of the first failing rule,
+    /// which is `None` if the value is valid or the native validation failed.
+    fn check(&self, value: &str, native: &NativeValidityState) -> (ValidityState, Option<&str>) {
+        let mut state = ValidityState::from(native);
+        if !state.valid() {
+            return (state, None);
+        }
+        let message = self
+            .rules
+            .iter()
+            .find(|rule| !rule.check(value, &mut state))
+            .map(|rule| rule.message.as_str());
+        (state, message)
+    }
+
+    /// Builds a [`ValidityTransform`] from these rules.
+    ///
+    /// This does not update the validation message, use the `validators` prop
+    /// of a field for that.
+    pub fn transform(&self) -> ValidityTransform {
+        let validators = self.clone();
+        ValidityTransform::new(move |value, native| validators.check(&value, &native).0)
+    }
+
+    /// Creates the `validityTransform` closure for the `validators` prop. The
+    /// message of the first failing rule is set as `validationMessage` of
+    /// `target`, otherwise the current `fallback_message` is used. A custom
+    /// validity message, e.g. from an [`AsyncValidator`](super::AsyncValidator),
+    /// is kept.
+    pub(crate) fn closure(
+        self,
+        target: web_sys::Element,
+        fallback_message: Rc<RefCell<Option<String>>>,
+    ) -> Closure<dyn Fn(String, NativeValidityState) -> ValidityStateJS> {
+        Closure::wrap(Box::new(
+            move |value: String, native: NativeValidityState| -> ValidityStateJS {
+                let (state, message) = self.check(&value, &native);
+                if native.custom_error() {
+                    return state.into();
+                }
+                let fallback_message = fallback_message.borrow();
+                let message = message.or(fallback_message.as_deref()).unwrap_or_default();
+                js_sys::Reflect::set(
+                    &target,
+                    &JsValue::from_str("validationMessage"),
+                    &JsValue::from_str(message),
+                )
+                .unwrap();
+                state.into()
+            },
+        )
+            as Box<dyn Fn(String, NativeValidityState) -> ValidityStateJS>)
+    }
+}
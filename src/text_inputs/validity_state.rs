@@ -2,6 +2,8 @@ use js_sys::Object;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 
+use super::NativeValidityState;
+
 #[wasm_bindgen]
 extern "C" {
     #[derive(Debug)]
@@ -23,13 +25,16 @@ extern "C" {
     #[wasm_bindgen(method, setter = rangeUnderflow)]
     pub fn set_range_underflow(this: &ValidityStateJS, val: bool);
 
-    #[wasm_bindgen(method, setter = too_long)]
+    #[wasm_bindgen(method, setter = stepMismatch)]
+    pub fn set_step_mismatch(this: &ValidityStateJS, val: bool);
+
+    #[wasm_bindgen(method, setter = tooLong)]
     pub fn set_too_long(this: &ValidityStateJS, val: bool);
 
     #[wasm_bindgen(method, setter = tooShort)]
     pub fn set_too_short(this: &ValidityStateJS, val: bool);
 
-    #[wasm_bindgen(method, setter = type_mismatch)]
+    #[wasm_bindgen(method, setter = typeMismatch)]
     pub fn set_type_mismatch(this: &ValidityStateJS, val: bool);
 
     #[wasm_bindgen(method, setter = valid)]
@@ -46,12 +51,17 @@ impl Default for ValidityStateJS {
 }
 
 /// Rust type for validity props
+///
+/// Can also be created from a [`NativeValidityState`] to read the state of a
+/// rendered field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ValidityState {
     bad_input: bool,
     custom_error: bool,
     pattern_mismatch: bool,
     range_overflow: bool,
     range_underflow: bool,
+    step_mismatch: bool,
     too_long: bool,
     too_short: bool,
     type_mismatch: bool,
@@ -71,6 +81,7 @@ impl ValidityState {
             pattern_mismatch: false,
             range_overflow: false,
             range_underflow: false,
+            step_mismatch: false,
             too_long: false,
             too_short: false,
             type_mismatch: false,
@@ -94,6 +105,9 @@ impl ValidityState {
     pub fn range_underflow(&self) -> bool {
         self.range_underflow
     }
+    pub fn step_mismatch(&self) -> bool {
+        self.step_mismatch
+    }
     pub fn too_long(&self) -> bool {
         self.too_long
     }
@@ -130,6 +144,10 @@ impl ValidityState {
         self.range_underflow = value;
         self
     }
+    pub fn set_step_mismatch(&mut self, value: bool) -> &mut Self {
+        self.step_mismatch = value;
+        self
+    }
     pub fn set_too_long(&mut self, value: bool) -> &mut Self {
         self.too_long = value;
         self
@@ -160,6 +178,7 @@ impl From<ValidityState> for ValidityStateJS {
         validity_state_js.set_pattern_mismatch(validity_state.pattern_mismatch());
         validity_state_js.set_range_overflow(validity_state.range_overflow());
         validity_state_js.set_range_underflow(validity_state.range_underflow());
+        validity_state_js.set_step_mismatch(validity_state.step_mismatch());
         validity_state_js.set_too_long(validity_state.too_long());
         validity_state_js.set_too_short(validity_state.too_short());
         validity_state_js.set_type_mismatch(validity_state.type_mismatch());
@@ -174,3 +193,27 @@ impl Default for ValidityState {
         Self::new()
     }
 }
+
+impl From<&NativeValidityState> for ValidityState {
+    fn from(native: &NativeValidityState) -> Self {
+        Self {
+            bad_input: native.bad_input(),
+            custom_error: native.custom_error(),
+            pattern_mismatch: native.pattern_mismatch(),
+            range_overflow: native.range_overflow(),
+            range_underflow: native.range_underflow(),
+            step_mismatch: native.step_mismatch(),
+            too_long: native.too_long(),
+            too_short: native.too_short(),
+            type_mismatch: native.type_mismatch(),
+            valid: native.valid(),
+            value_missing: native.value_missing(),
+        }
+    }
+}
+
+impl From<NativeValidityState> for ValidityState {
+    fn from(native: NativeValidityState) -> Self {
+        Self::from(&native)
+    }
+}
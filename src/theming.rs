@@ -5,6 +5,24 @@ use dioxus::prelude::*;
 
 use crate::palette::{self, Color};
 
+mod density;
+mod shape;
+mod theme_mode;
+mod theme_scope;
+mod tonal_palette;
+mod typography;
+pub(crate) use density::use_current_density;
+pub use density::{use_density, Density, UseDensity};
+use density::{use_density_prop, DensityState};
+pub use shape::Shape;
+use theme_mode::ThemeModeState;
+pub use theme_mode::{
+    use_persisted_theme_mode, use_theme_mode, ParseThemeModeError, ThemeMode, UseThemeMode,
+};
+pub use theme_scope::{MatThemeScope, ThemeScopeProps};
+pub use tonal_palette::TonalPalette;
+pub use typography::{TypeScale, Typography};
+
 #[derive(Debug, PartialEq)]
 pub struct Colors {
     pub primary: Color,
@@ -54,6 +72,83 @@ impl Colors {
         error: palette::from_u32(0xcf6679, 1.),
         ..Self::DEFAULT_LIGHT
     };
+
+    /// Creates a light color scheme from a single brand color, following the
+    /// tonal palettes of the Material 3 color system.
+    ///
+    /// Use [`from_seed_dark`](Self::from_seed_dark) with the same `seed` for the
+    /// matching dark scheme.
+    ///
+    /// ```
+    /// use material_dioxus::{palette, theming::Colors};
+    ///
+    /// let light = Colors::from_seed(palette::from_u32(0x6750a4, 1.));
+    /// let dark = Colors::from_seed_dark(palette::from_u32(0x6750a4, 1.));
+    /// assert_ne!(light.primary, dark.primary);
+    /// ```
+    pub fn from_seed(seed: Color) -> Self {
+        let palettes = SeedPalettes::new(seed);
+        Self::from_tones(&palettes, [40., 100., 99., 10., 20., 95.])
+    }
+
+    /// Creates a dark color scheme from a single brand color, the counterpart
+    /// of [`from_seed`](Self::from_seed).
+    pub fn from_seed_dark(seed: Color) -> Self {
+        let palettes = SeedPalettes::new(seed);
+        Self::from_tones(&palettes, [80., 20., 10., 90., 90., 20.])
+    }
+
+    /// `tones` are the tones of the accent colors, the colors on them, the
+    /// surface, the color on the surface, the inverse surface and the color on
+    /// the inverse surface.
+    fn from_tones(palettes: &SeedPalettes, tones: [f32; 6]) -> Self {
+        let [accent, on_accent, surface, on_surface, inverse_surface, on_inverse_surface] = tones;
+        Self {
+            primary: palettes.primary.tone(accent),
+            secondary: Some(palettes.secondary.tone(accent)),
+            surface: Some(palettes.neutral.tone(surface)),
+            inverse_surface: palettes.neutral.tone(inverse_surface),
+            background: palettes.neutral.tone(surface),
+            error: palettes.error.tone(accent),
+
+            on_primary: Some(palettes.primary.tone(on_accent)),
+            on_secondary: Some(palettes.secondary.tone(on_accent)),
+            on_surface: Some(palettes.neutral.tone(on_surface)),
+            on_inverse_surface: Some(palettes.neutral.tone(on_inverse_surface)),
+            on_error: Some(palettes.error.tone(on_accent)),
+
+            four_color_progress: [
+                palettes.primary.tone(accent),
+                palettes.secondary.tone(accent),
+                palettes.tertiary.tone(accent),
+                palettes.error.tone(accent),
+            ],
+            switch_use_secondary: false,
+        }
+    }
+}
+
+/// The tonal palettes of a color scheme, derived from the seed like the
+/// "tonal spot" scheme of Material 3
+struct SeedPalettes {
+    primary: TonalPalette,
+    secondary: TonalPalette,
+    tertiary: TonalPalette,
+    neutral: TonalPalette,
+    error: TonalPalette,
+}
+
+impl SeedPalettes {
+    fn new(seed: Color) -> Self {
+        let hue = TonalPalette::from_seed(seed).hue();
+        Self {
+            primary: TonalPalette::new(hue, 48.),
+            secondary: TonalPalette::new(hue, 16.),
+            tertiary: TonalPalette::new(hue + 60., 24.),
+            neutral: TonalPalette::new(hue, 4.),
+            error: TonalPalette::new(25., 84.),
+        }
+    }
 }
 
 #[derive(Props, PartialEq)]
@@ -62,6 +157,16 @@ pub struct ThemeProps {
     theme: Colors,
     #[props(!optional, default = Some(Colors::DEFAULT_DARK))]
     dark_theme: Option<Colors>,
+    /// The fonts of all components, the defaults of the components are used
+    /// without it
+    typography: Option<Typography>,
+    /// The corner radii of all components, the defaults of the components are
+    /// used without it
+    shape: Option<Shape>,
+    /// The density of all components with a `dense` prop which is not set.
+    /// Only applies to the components below the caller of [`use_density`].
+    #[props(default)]
+    density: Density,
 }
 
 #[derive(Clone, Copy)]
@@ -97,8 +202,29 @@ fn mix(col1: Color, col2: Color, factor: f32) -> Color {
     col2.mix(col1, factor).into_format()
 }
 
+/// Generates the CSS for `light` and `dark` according to `mode`.
+fn theme_css(light: &Colors, dark: Option<&Colors>, mode: ThemeMode, selector: &str) -> String {
+    match (mode, dark) {
+        (ThemeMode::Dark, Some(colors)) => define_vars(colors, selector),
+        (ThemeMode::System, Some(colors)) => format!(
+            "{}@media screen and (prefers-color-scheme: dark) {{{}}}",
+            define_vars(light, selector),
+            define_vars(colors, selector)
+        ),
+        (ThemeMode::Light, _) | (_, None) => define_vars(light, selector),
+    }
+}
+
+/// Defines the CSS variables for `colors` on the elements matching `selector`.
 #[rustfmt::skip]
-fn define_vars(colors: &Colors) -> String {
+fn define_vars(colors: &Colors, selector: &str) -> String {
+    // the component specific overrides must be more specific than the ones of
+    // `:root` when used in a scope
+    let descendant = match selector {
+        ":root" => String::new(),
+        _ => format!("{selector} "),
+    };
+
     let primary = ColorDisplay(colors.primary);
     let secondary = ColorDisplay(colors.secondary.unwrap_or(colors.primary));
     let surface = ColorDisplay(colors.surface.unwrap_or(colors.background));
@@ -175,7 +301,7 @@ fn define_vars(colors: &Colors) -> String {
 
     format!(
         "
-:root {{
+{selector} {{
     --mdc-theme-primary: {primary};
     --mdc-theme-secondary: {secondary};
     --mdc-theme-surface: {surface};
@@ -269,30 +395,37 @@ fn define_vars(colors: &Colors) -> String {
     --mdc-deprecated-list-divider-color: {list_divider};
 }}
 
-mwc-switch {{
+{descendant}mwc-switch {{
     --mdc-theme-primary: {switch_primary};
 }}
 
-mwc-list {{
+{descendant}mwc-list {{
     --mdc-ripple-color: {list_ripple};
 }}
 "
     )
 }
 
+/// Sets the CSS variables for the colors of all components.
+///
+/// The dark theme is applied according to the [`ThemeMode`] of
+/// [`use_theme_mode`], by default when the user agent prefers a dark color
+/// scheme.
 #[allow(non_snake_case)]
 pub fn MatTheme(cx: Scope<ThemeProps>) -> Element {
-    let light_theme = define_vars(&cx.props.theme);
-    let dark_theme = if let Some(colors) = &cx.props.dark_theme {
-        format!(
-            "@media screen and (prefers-color-scheme: dark) {{{}}}",
-            define_vars(colors)
-        )
-    } else {
-        String::new()
-    };
+    let mode =
+        use_shared_state::<ThemeModeState>(cx).map_or(ThemeMode::System, |state| state.read().mode);
+    let mut css = theme_css(&cx.props.theme, cx.props.dark_theme.as_ref(), mode, ":root");
+    if let Some(typography) = &cx.props.typography {
+        css += &format!(":root {{\n{}}}\n", typography.define_vars());
+    }
+    if let Some(shape) = &cx.props.shape {
+        css += &format!(":root {{\n{}}}\n", shape.define_vars());
+    }
+    let density = use_shared_state::<DensityState>(cx);
+    use_density_prop(cx, density, cx.props.density);
 
     render! {
-        style { dangerous_inner_html: "{light_theme}{dark_theme}" }
+        style { dangerous_inner_html: "{css}" }
     }
 }
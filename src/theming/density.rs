@@ -0,0 +1,98 @@
+use dioxus::prelude::*;
+
+/// The density of all components supporting it, passed to the `density` prop
+/// of [`MatTheme`](super::MatTheme) or [`MatThemeScope`](super::MatThemeScope)
+///
+/// Single components can override it with their `dense` prop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Density {
+    #[default]
+    Normal,
+    Compact,
+}
+
+pub(super) struct DensityState {
+    pub(super) density: Density,
+}
+
+/// The [`Density`] shared by all components below the one that first called
+/// [`use_density`]
+#[derive(Clone)]
+pub struct UseDensity {
+    state: UseSharedState<DensityState>,
+}
+
+impl UseDensity {
+    pub fn get(&self) -> Density {
+        self.state.read().density
+    }
+
+    /// Switches the density, which re-renders all components using it.
+    pub fn set(&self, density: Density) {
+        self.state.write().density = density;
+    }
+}
+
+/// Returns the current [`Density`] for switching it at runtime.
+///
+/// Call this in the component rendering [`MatTheme`](super::MatTheme) or in
+/// one of its ancestors, the density is then shared with all components
+/// below and the `density` prop of `MatTheme` applies to them. Defaults to
+/// [`Density::Normal`].
+///
+/// ```
+/// use dioxus::prelude::*;
+/// use material_dioxus::{theming::{use_density, Density}, MatButton, MatTheme};
+///
+/// #[allow(non_snake_case)]
+/// fn App(cx: Scope) -> Element {
+///     use_density(cx);
+///
+///     render! {
+///         MatTheme { density: Density::Compact }
+///         MatButton { label: "compact" }
+///     }
+/// }
+/// ```
+pub fn use_density(cx: &ScopeState) -> &UseDensity {
+    // only the first caller provides the state, whether it exists never
+    // changes for a component, so the order of the hooks is stable
+    let state = match use_shared_state::<DensityState>(cx) {
+        Some(state) => state,
+        None => {
+            use_shared_state_provider(cx, || DensityState {
+                density: Density::Normal,
+            });
+            use_shared_state::<DensityState>(cx).unwrap()
+        }
+    };
+    cx.use_hook(|| UseDensity {
+        state: state.clone(),
+    })
+}
+
+/// Sets the shared density to `density` whenever `density` changes, so
+/// switching it with [`UseDensity::set`] is kept until then.
+pub(super) fn use_density_prop(
+    cx: &ScopeState,
+    state: Option<&UseSharedState<DensityState>>,
+    density: Density,
+) {
+    let last = cx.use_hook(|| None);
+    if *last == Some(density) {
+        return;
+    }
+    *last = Some(density);
+    if let Some(state) = state {
+        if state.read().density != density {
+            state.write().density = density;
+        }
+    }
+}
+
+/// The shared density, subscribing the component to changes.
+/// [`Density::Normal`] without [`use_density`] or a
+/// [`MatThemeScope`](super::MatThemeScope).
+pub(crate) fn use_current_density(cx: &ScopeState) -> Density {
+    use_shared_state::<DensityState>(cx).map_or(Density::Normal, |state| state.read().density)
+}
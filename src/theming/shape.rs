@@ -0,0 +1,48 @@
+use std::borrow::Cow;
+
+/// The corner radii of all components, passed to the `shape` prop of
+/// [`MatTheme`](super::MatTheme)
+///
+/// Sets the `--mdc-shape-*` CSS variables. Values are CSS lengths like `12px`.
+/// Single components can override it with their `corner_radius` prop.
+///
+/// ```
+/// use material_dioxus::theming::Shape;
+///
+/// let rounded = Shape {
+///     small: "12px".into(),
+///     medium: "12px".into(),
+///     ..Shape::DEFAULT
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shape {
+    /// Buttons, text fields and selects
+    pub small: Cow<'static, str>,
+    /// Dialogs and menus
+    pub medium: Cow<'static, str>,
+    /// Drawers
+    pub large: Cow<'static, str>,
+}
+
+impl Shape {
+    pub const DEFAULT: Self = Self {
+        small: Cow::Borrowed("4px"),
+        medium: Cow::Borrowed("4px"),
+        large: Cow::Borrowed("0"),
+    };
+
+    /// The CSS variable declarations, without a selector.
+    pub(super) fn define_vars(&self) -> String {
+        format!(
+            "    --mdc-shape-small: {};\n    --mdc-shape-medium: {};\n    --mdc-shape-large: {};\n",
+            self.small, self.medium, self.large
+        )
+    }
+}
+
+impl Default for Shape {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt;
+use std::str::FromStr;
+
+use dioxus::prelude::*;
+use gloo::storage::{LocalStorage, Storage};
+
+/// Whether [`MatTheme`](super::MatTheme) applies the light or the dark theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ThemeMode {
+    Light,
+    Dark,
+    /// Follow the `prefers-color-scheme` of the user agent
+    #[default]
+    System,
+}
+
+impl ThemeMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThemeMode::Light => "light",
+            ThemeMode::Dark => "dark",
+            ThemeMode::System => "system",
+        }
+    }
+}
+
+impl fmt::Display for ThemeMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Error when parsing a [`ThemeMode`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseThemeModeError;
+
+impl fmt::Display for ParseThemeModeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected one of `light`, `dark` or `system`")
+    }
+}
+
+impl std::error::Error for ParseThemeModeError {}
+
+impl FromStr for ThemeMode {
+    type Err = ParseThemeModeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "light" => Ok(ThemeMode::Light),
+            "dark" => Ok(ThemeMode::Dark),
+            "system" => Ok(ThemeMode::System),
+            _ => Err(ParseThemeModeError),
+        }
+    }
+}
+
+pub(super) struct ThemeModeState {
+    pub(super) mode: ThemeMode,
+    storage_key: Option<String>,
+}
+
+/// The [`ThemeMode`] shared by all components below the one that first called
+/// [`use_theme_mode`] or [`use_persisted_theme_mode`]
+#[derive(Clone)]
+pub struct UseThemeMode {
+    state: UseSharedState<ThemeModeState>,
+}
+
+impl UseThemeMode {
+    pub fn get(&self) -> ThemeMode {
+        self.state.read().mode
+    }
+
+    /// Switches the theme mode, which re-renders [`MatTheme`](super::MatTheme)
+    /// and all other users of the theme mode. The mode is stored if it was
+    /// created by [`use_persisted_theme_mode`].
+    pub fn set(&self, mode: ThemeMode) {
+        let mut state = self.state.write();
+        state.mode = mode;
+        if let Some(key) = &state.storage_key {
+            LocalStorage::raw().set_item(key, mode.as_str()).ok();
+        }
+    }
+}
+
+/// Returns the current [`ThemeMode`] for switching it at runtime.
+///
+/// Call this in the component rendering [`MatTheme`](super::MatTheme) or in
+/// one of its ancestors, the mode is then shared with all components below.
+/// Defaults to [`ThemeMode::System`].
+///
+/// ```
+/// use dioxus::prelude::*;
+/// use material_dioxus::{theming::{use_theme_mode, ThemeMode}, MatButton, MatTheme};
+///
+/// #[allow(non_snake_case)]
+/// fn App(cx: Scope) -> Element {
+///     let theme_mode = use_theme_mode(cx);
+///
+///     render! {
+///         MatTheme {}
+///         span {
+///             onclick: move |_| theme_mode.set(ThemeMode::Dark),
+///             MatButton { label: "dark mode" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_theme_mode(cx: &ScopeState) -> &UseThemeMode {
+    use_theme_mode_with(cx, || ThemeModeState {
+        mode: ThemeMode::System,
+        storage_key: None,
+    })
+}
+
+/// Like [`use_theme_mode`], but the mode is stored in `localStorage` under
+/// `storage_key` and restored from there on the next visit.
+pub fn use_persisted_theme_mode(cx: &ScopeState, storage_key: impl Into<String>) -> &UseThemeMode {
+    use_theme_mode_with(cx, || {
+        let storage_key = storage_key.into();
+        let mode = LocalStorage::raw()
+            .get_item(&storage_key)
+            .ok()
+            .flatten()
+            .and_then(|mode| mode.parse().ok())
+            .unwrap_or_default();
+        ThemeModeState {
+            mode,
+            storage_key: Some(storage_key),
+        }
+    })
+}
+
+fn use_theme_mode_with(cx: &ScopeState, init: impl FnOnce() -> ThemeModeState) -> &UseThemeMode {
+    // only the first caller provides the state, whether it exists never
+    // changes for a component, so the order of the hooks is stable
+    let state = match use_shared_state::<ThemeModeState>(cx) {
+        Some(state) => state,
+        None => {
+            use_shared_state_provider(cx, init);
+            use_shared_state::<ThemeModeState>(cx).unwrap()
+        }
+    };
+    cx.use_hook(|| UseThemeMode {
+        state: state.clone(),
+    })
+}
@@ -0,0 +1,93 @@
+use dioxus::prelude::*;
+
+use super::density::{use_current_density, use_density_prop, DensityState};
+use super::{theme_css, Colors, Density, ThemeMode, ThemeModeState};
+
+#[derive(Props)]
+pub struct ThemeScopeProps<'a> {
+    /// The colors used inside the scope
+    pub theme: Colors,
+    /// The colors used inside the scope in dark mode, see [`ThemeMode`].
+    /// Without them, `theme` is also used in dark mode, e.g. for a sidebar
+    /// that is always dark.
+    pub dark_theme: Option<Colors>,
+    /// Applies the theme to all elements with this class instead of a
+    /// wrapping `div` around the children. `style` and `class` are unused
+    /// then.
+    #[props(into)]
+    pub scope_class: Option<String>,
+    /// The density inside the scope, by default the one outside of it
+    pub density: Option<Density>,
+    #[props(default)]
+    pub children: Element<'a>,
+
+    #[props(into, default)]
+    pub style: String,
+    #[props(into, default)]
+    pub class: String,
+}
+
+/// Applies a color theme to a part of the page, like [`MatTheme`](super::MatTheme)
+/// does for the whole page.
+///
+/// Renders its children in a `div` which gets the background and text color of
+/// the theme, so it can be used for inverted surfaces like a dark sidebar
+/// next to light content.
+///
+/// ```
+/// use dioxus::prelude::*;
+/// use material_dioxus::{theming::Colors, MatButton, MatThemeScope};
+///
+/// #[allow(non_snake_case)]
+/// fn Sidebar(cx: Scope) -> Element {
+///     render! {
+///         MatThemeScope {
+///             theme: Colors::DEFAULT_DARK,
+///             MatButton { label: "always dark" }
+///         }
+///     }
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn MatThemeScope<'a>(cx: Scope<'a, ThemeScopeProps<'a>>) -> Element<'a> {
+    let id = crate::use_id(cx, "theme-scope");
+    let mode =
+        use_shared_state::<ThemeModeState>(cx).map_or(ThemeMode::System, |state| state.read().mode);
+    // read the density outside before providing the one inside
+    let outside = use_current_density(cx);
+    let density = cx.props.density.unwrap_or(outside);
+    use_shared_state_provider(cx, || DensityState { density });
+    use_density_prop(cx, use_shared_state::<DensityState>(cx), density);
+    let scope_class = cx.props.scope_class.as_deref().unwrap_or(id);
+    let selector = format!(".{scope_class}");
+    let css = format!(
+        "{}
+{selector} {{
+    background-color: var(--mdc-theme-background);
+    color: var(--mdc-theme-text-primary-on-background);
+}}
+",
+        theme_css(
+            &cx.props.theme,
+            cx.props.dark_theme.as_ref(),
+            mode,
+            &selector
+        )
+    );
+
+    match &cx.props.scope_class {
+        Some(_) => render! {
+            style { dangerous_inner_html: "{css}" }
+            &cx.props.children
+        },
+        None => render! {
+            style { dangerous_inner_html: "{css}" }
+            div {
+                class: "{id} {cx.props.class}",
+                style: string_attr!(cx.props.style),
+
+                &cx.props.children
+            }
+        },
+    }
+}
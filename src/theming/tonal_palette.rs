@@ -0,0 +1,82 @@
+use palette::{convert::FromColorUnclamped, IntoColor, Lch, Srgb};
+
+use crate::palette::Color;
+
+/// Colors of one hue and chroma in varying tones, used by
+/// [`Colors::from_seed`](super::Colors::from_seed)
+///
+/// Approximates the tonal palettes of Material 3 in the CIE LCh color space,
+/// where the lightness is the tone. Chroma that cannot be displayed in sRGB at
+/// a tone is reduced.
+///
+/// ```
+/// use material_dioxus::{palette, theming::TonalPalette};
+///
+/// let purple = TonalPalette::from_seed(palette::PURPLE_500);
+/// assert_eq!(purple.tone(100.), palette::from_u32(0xffffff, 1.));
+/// assert_eq!(purple.tone(0.), palette::from_u32(0x000000, 1.));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TonalPalette {
+    hue: f32,
+    chroma: f32,
+}
+
+impl TonalPalette {
+    /// Creates a palette from a hue in degrees and a chroma.
+    pub fn new(hue: f32, chroma: f32) -> Self {
+        Self { hue, chroma }
+    }
+
+    /// Creates a palette with the hue and chroma of `seed`.
+    pub fn from_seed(seed: Color) -> Self {
+        let lch: Lch = seed.color.into_format::<f32>().into_color();
+        Self::new(lch.hue.into_positive_degrees(), lch.chroma)
+    }
+
+    pub fn hue(&self) -> f32 {
+        self.hue
+    }
+
+    pub fn chroma(&self) -> f32 {
+        self.chroma
+    }
+
+    /// The color at `tone` from 0 (black) to 100 (white).
+    pub fn tone(&self, tone: f32) -> Color {
+        let tone = tone.clamp(0., 100.);
+        let to_srgb = |chroma: f32| Srgb::from_color_unclamped(Lch::new(tone, chroma, self.hue));
+
+        let mut srgb = to_srgb(self.chroma);
+        if !in_gamut(srgb) {
+            // binary search for the highest chroma inside the sRGB gamut
+            let (mut low, mut high) = (0., self.chroma);
+            srgb = to_srgb(low);
+            for _ in 0..16 {
+                let chroma = (low + high) / 2.;
+                let candidate = to_srgb(chroma);
+                if in_gamut(candidate) {
+                    low = chroma;
+                    srgb = candidate;
+                } else {
+                    high = chroma;
+                }
+            }
+        }
+        let srgb = Srgb::new(
+            srgb.red.clamp(0., 1.),
+            srgb.green.clamp(0., 1.),
+            srgb.blue.clamp(0., 1.),
+        );
+        Color::from(srgb.into_format::<u8>())
+    }
+}
+
+/// Whether `srgb` can be displayed, with some tolerance for rounding errors of
+/// the conversion, e.g. white at tone 100.
+fn in_gamut(srgb: Srgb) -> bool {
+    const TOLERANCE: f32 = 1e-4;
+    [srgb.red, srgb.green, srgb.blue]
+        .iter()
+        .all(|component| (-TOLERANCE..=1. + TOLERANCE).contains(component))
+}
@@ -0,0 +1,145 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+/// The fonts of all components, passed to the `typography` prop of
+/// [`MatTheme`](super::MatTheme)
+///
+/// Sets the `--mdc-typography-*` CSS variables. The defaults are the ones of
+/// the Material Design type scale with Roboto.
+///
+/// ```
+/// use material_dioxus::theming::{TypeScale, Typography};
+///
+/// let typography = Typography {
+///     button: TypeScale {
+///         font_family: Some("'Fira Sans', sans-serif".into()),
+///         text_transform: "none".into(),
+///         ..Typography::DEFAULT.button
+///     },
+///     ..Typography::with_font_family("Inter, sans-serif")
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Typography {
+    /// The font family of all scales without their own `font_family`
+    pub font_family: Cow<'static, str>,
+    pub headline1: TypeScale,
+    pub headline2: TypeScale,
+    pub headline3: TypeScale,
+    pub headline4: TypeScale,
+    pub headline5: TypeScale,
+    pub headline6: TypeScale,
+    pub subtitle1: TypeScale,
+    pub subtitle2: TypeScale,
+    pub body1: TypeScale,
+    pub body2: TypeScale,
+    pub caption: TypeScale,
+    pub button: TypeScale,
+    pub overline: TypeScale,
+}
+
+/// One scale of a [`Typography`], e.g. the one for buttons
+///
+/// Sizes and spacings are CSS values like `0.875rem`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeScale {
+    /// Overrides the `font_family` of the [`Typography`]
+    pub font_family: Option<Cow<'static, str>>,
+    pub font_size: Cow<'static, str>,
+    pub line_height: Cow<'static, str>,
+    pub font_weight: u16,
+    pub letter_spacing: Cow<'static, str>,
+    pub text_transform: Cow<'static, str>,
+}
+
+impl TypeScale {
+    const fn new(
+        font_size: &'static str,
+        line_height: &'static str,
+        font_weight: u16,
+        letter_spacing: &'static str,
+        text_transform: &'static str,
+    ) -> Self {
+        Self {
+            font_family: None,
+            font_size: Cow::Borrowed(font_size),
+            line_height: Cow::Borrowed(line_height),
+            font_weight,
+            letter_spacing: Cow::Borrowed(letter_spacing),
+            text_transform: Cow::Borrowed(text_transform),
+        }
+    }
+}
+
+impl Typography {
+    pub const DEFAULT: Self = Self {
+        font_family: Cow::Borrowed("Roboto, sans-serif"),
+        headline1: TypeScale::new("6rem", "6rem", 300, "-0.015625em", "inherit"),
+        headline2: TypeScale::new("3.75rem", "3.75rem", 300, "-0.0083333333em", "inherit"),
+        headline3: TypeScale::new("3rem", "3.125rem", 400, "normal", "inherit"),
+        headline4: TypeScale::new("2.125rem", "2.5rem", 400, "0.0073529412em", "inherit"),
+        headline5: TypeScale::new("1.5rem", "2rem", 400, "normal", "inherit"),
+        headline6: TypeScale::new("1.25rem", "2rem", 500, "0.0125em", "inherit"),
+        subtitle1: TypeScale::new("1rem", "1.75rem", 400, "0.009375em", "inherit"),
+        subtitle2: TypeScale::new("0.875rem", "1.375rem", 500, "0.0071428571em", "inherit"),
+        body1: TypeScale::new("1rem", "1.5rem", 400, "0.03125em", "inherit"),
+        body2: TypeScale::new("0.875rem", "1.25rem", 400, "0.0178571429em", "inherit"),
+        caption: TypeScale::new("0.75rem", "1.25rem", 400, "0.0333333333em", "inherit"),
+        button: TypeScale::new("0.875rem", "2.25rem", 500, "0.0892857143em", "uppercase"),
+        overline: TypeScale::new("0.75rem", "2rem", 500, "0.1666666667em", "uppercase"),
+    };
+
+    /// The default type scale with another font family.
+    pub fn with_font_family(font_family: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            font_family: font_family.into(),
+            ..Self::DEFAULT
+        }
+    }
+
+    /// The CSS variable declarations, without a selector.
+    pub(super) fn define_vars(&self) -> String {
+        let mut vars = format!("    --mdc-typography-font-family: {};\n", self.font_family);
+        for (name, scale) in [
+            ("headline1", &self.headline1),
+            ("headline2", &self.headline2),
+            ("headline3", &self.headline3),
+            ("headline4", &self.headline4),
+            ("headline5", &self.headline5),
+            ("headline6", &self.headline6),
+            ("subtitle1", &self.subtitle1),
+            ("subtitle2", &self.subtitle2),
+            ("body1", &self.body1),
+            ("body2", &self.body2),
+            ("caption", &self.caption),
+            ("button", &self.button),
+            ("overline", &self.overline),
+        ] {
+            let prefix = format!("--mdc-typography-{name}");
+            let font_family = scale.font_family.as_ref().unwrap_or(&self.font_family);
+            writeln!(vars, "    {prefix}-font-family: {font_family};").unwrap();
+            writeln!(vars, "    {prefix}-font-size: {};", scale.font_size).unwrap();
+            writeln!(vars, "    {prefix}-line-height: {};", scale.line_height).unwrap();
+            writeln!(vars, "    {prefix}-font-weight: {};", scale.font_weight).unwrap();
+            writeln!(
+                vars,
+                "    {prefix}-letter-spacing: {};",
+                scale.letter_spacing
+            )
+            .unwrap();
+            writeln!(
+                vars,
+                "    {prefix}-text-transform: {};",
+                scale.text_transform
+            )
+            .unwrap();
+        }
+        vars
+    }
+}
+
+impl Default for Typography {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -1,98 +1,127 @@
 mod action_items;
 mod navigation_icon;
+mod scroll_target;
 mod title;
 
 pub use action_items::*;
 pub use navigation_icon::*;
+pub use scroll_target::ScrollTarget;
 pub use title::*;
 
-use crate::bool_to_option;
+use dioxus::prelude::*;
 use gloo::events::EventListener;
 use wasm_bindgen::prelude::*;
-use web_sys::Element;
-use yew::prelude::*;
+use web_sys::Node;
+
+use crate::StaticCallback;
 
 #[wasm_bindgen(module = "/build/mwc-top-app-bar.js")]
 extern "C" {
     #[derive(Debug)]
+    #[wasm_bindgen(extends = Node)]
     type TopAppBar;
 
     #[wasm_bindgen(getter, static_method_of = TopAppBar)]
     fn _dummy_loader() -> JsValue;
+
+    #[wasm_bindgen(method, setter = scrollTarget)]
+    fn set_scroll_target(this: &TopAppBar, value: &JsValue);
 }
 
 loader_hack!(TopAppBar);
 
-/// The `mwc-top-app-bar` component
-///
-/// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/top-app-bar)
-pub struct MatTopAppBar {
-    node_ref: NodeRef,
-    nav_listener: Option<EventListener>,
-}
-
 /// Props for [`MatTopAppBar`]
 ///
 /// MWC Documentation:
 ///
 /// - [Properties](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/top-app-bar#propertiesattributes)
 /// - [Events](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/top-app-bar#events)
-#[derive(Debug, Properties, PartialEq, Clone)]
-pub struct TopAppBarProps {
-    pub children: Children,
-    #[prop_or_default]
+#[derive(Props)]
+pub struct TopAppBarProps<'a> {
+    #[props(default)]
     pub center_title: bool,
-    #[prop_or_default]
-    pub dense: bool,
-    #[prop_or_default]
+    /// `None` uses the shared [`Density`](crate::theming::Density) of the
+    /// theme, `Some(false)` keeps the normal density even in a compact theme.
+    pub dense: Option<bool>,
+    #[props(default)]
     pub prominent: bool,
+    /// The element whose scrolling is tracked. Either the `id` of a rendered
+    /// element or an element obtained with [`ScrollTarget::from_mounted`].
+    /// Defaults to `window`.
+    #[props(into)]
+    pub scroll_target: Option<ScrollTarget>,
     /// Binds to `MDCTopAppBar:nav`
     ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub onnavigationiconclick: Callback<()>,
-}
+    #[props(into)]
+    // the name cannot start with `on` or dioxus will expect an `EventHandler` which aren't static
+    // and thus cannot be used here
+    pub _onnavigation_icon_click: Option<StaticCallback<()>>,
+    pub children: Element<'a>,
 
-impl Component for MatTopAppBar {
-    type Message = ();
-    type Properties = TopAppBarProps;
+    #[props(into, default)]
+    pub style: String,
+    #[props(into, default)]
+    pub class: String,
+}
 
-    fn create(_: &Context<Self>) -> Self {
-        TopAppBar::ensure_loaded();
-        Self {
-            node_ref: NodeRef::default(),
-            nav_listener: None,
+fn render<'a>(cx: Scope<'a, TopAppBarProps<'a>>) -> Element<'a> {
+    let id = crate::use_id(cx, "top-app-bar");
+    let nav_listener = cx.use_hook(|| None);
+    let last_scroll_target = cx.use_hook(|| None);
+    if let Some(elem) = crate::get_elem_by_id(id) {
+        let target = elem.clone();
+        let top_app_bar = JsValue::from(elem).dyn_into::<TopAppBar>().unwrap();
+        update_scroll_target(last_scroll_target, &cx.props.scroll_target, |value| {
+            top_app_bar.set_scroll_target(value)
+        });
+        if let Some(listener) = cx.props._onnavigation_icon_click.clone() {
+            *nav_listener = Some(EventListener::new(&target, "MDCTopAppBar:nav", move |_| {
+                listener.call(())
+            }));
         }
     }
 
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props();
-        html! {
-             <mwc-top-app-bar
-                 centerTitle={bool_to_option(props.center_title)}
-                 dense={bool_to_option(props.dense)}
-                 prominent={bool_to_option(props.prominent)}
-                 ref={self.node_ref.clone()}
-             >
-                 {props.children.clone()}
-             </mwc-top-app-bar>
-        }
-    }
+    let dense = crate::utils::dense(cx, cx.props.dense);
+
+    render! {
+        mwc-top-app-bar {
+            id: id,
 
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        let props = ctx.props();
+            centerTitle: bool_attr!(cx.props.center_title),
+            dense: bool_attr!(dense),
+            prominent: bool_attr!(cx.props.prominent),
 
-        if self.nav_listener.is_none() {
-            let callback = props.onnavigationiconclick.clone();
-            let element = self.node_ref.cast::<Element>().unwrap();
+            style: string_attr!(cx.props.style),
+            class: string_attr!(cx.props.class),
 
-            self.nav_listener = Some(EventListener::new(
-                &element,
-                "MDCTopAppBar:nav",
-                move |_| {
-                    callback.emit(());
-                },
-            ));
+            &cx.props.children
         }
     }
 }
+
+component!('a, MatTopAppBar, TopAppBarProps, render, TopAppBar, "top-app-bar");
+
+/// Calls `set` with the resolved `scrollTarget` if it differs from `last`, as
+/// setting it makes MWC re-register its scroll listener.
+pub(crate) fn update_scroll_target(
+    last: &mut Option<JsValue>,
+    scroll_target: &Option<ScrollTarget>,
+    set: impl FnOnce(&JsValue),
+) {
+    let value = scroll_target_value(scroll_target);
+    if last.as_ref() != Some(&value) {
+        set(&value);
+        *last = Some(value);
+    }
+}
+
+/// Resolves the `scrollTarget` property value, falling back to `window`.
+fn scroll_target_value(scroll_target: &Option<ScrollTarget>) -> JsValue {
+    scroll_target
+        .as_ref()
+        .and_then(ScrollTarget::element)
+        .map(JsValue::from)
+        .or_else(|| web_sys::window().map(JsValue::from))
+        .unwrap_or(JsValue::UNDEFINED)
+}
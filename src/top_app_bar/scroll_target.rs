@@ -0,0 +1,5 @@
+/// Type for [`TopAppBarProps::scroll_target`][super::TopAppBarProps::scroll_target]
+/// and [`TopAppBarFixedProps::scroll_target`][crate::top_app_bar_fixed::TopAppBarFixedProps::scroll_target].
+///
+/// Strings are converted to [`ScrollTarget::Id`].
+pub type ScrollTarget = crate::ElementRef;
@@ -1,100 +1,108 @@
-use crate::bool_to_option;
 #[doc(inline)]
 pub use crate::top_app_bar::{
-    MatTopAppBarActionItems, MatTopAppBarNavigationIcon, MatTopAppBarTitle,
+    MatTopAppBarActionItems, MatTopAppBarNavigationIcon, MatTopAppBarTitle, ScrollTarget,
 };
+
+use crate::top_app_bar::update_scroll_target;
+use crate::StaticCallback;
+use dioxus::prelude::*;
 use gloo::events::EventListener;
 use wasm_bindgen::prelude::*;
-use web_sys::Element;
-use yew::prelude::*;
+use web_sys::Node;
 
 #[wasm_bindgen(module = "/build/mwc-top-app-bar-fixed.js")]
 extern "C" {
     #[derive(Debug)]
+    #[wasm_bindgen(extends = Node)]
     type TopAppBarFixed;
 
     #[wasm_bindgen(getter, static_method_of = TopAppBarFixed)]
     fn _dummy_loader() -> JsValue;
+
+    #[wasm_bindgen(method, setter = scrollTarget)]
+    fn set_scroll_target(this: &TopAppBarFixed, value: &JsValue);
 }
 
 loader_hack!(TopAppBarFixed);
 
-/// The `mwc-top-app-bar-fixed` component
-///
-/// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/top-app-bar-fixed)
-pub struct MatTopAppBarFixed {
-    node_ref: NodeRef,
-    nav_listener: Option<EventListener>,
-}
-
 /// Props for [`MatTopAppBarFixed`]
 ///
 /// MWC Documentation:
 ///
 /// - [Properties](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/top-app-bar-fixed#propertiesattributes)
 /// - [Events](https://github.com/material-components/material-components-web-components/tree/v0.27.0/packages/top-app-bar-fixed#events)
-#[derive(Debug, Properties, PartialEq, Clone)]
-pub struct TopAppBarFixedProps {
-    pub children: Children,
-    #[prop_or_default]
+#[derive(Props)]
+pub struct TopAppBarFixedProps<'a> {
+    #[props(default)]
     pub center_title: bool,
-    #[prop_or_default]
-    pub dense: bool,
-    #[prop_or_default]
+    /// `None` uses the shared [`Density`](crate::theming::Density) of the
+    /// theme, `Some(false)` keeps the normal density even in a compact theme.
+    pub dense: Option<bool>,
+    #[props(default)]
     pub prominent: bool,
-    #[prop_or_default]
+    #[props(default)]
     pub short: bool,
+    /// The element whose scrolling is tracked. Either the `id` of a rendered
+    /// element or an element obtained with [`ScrollTarget::from_mounted`].
+    /// Defaults to `window`.
+    #[props(into)]
+    pub scroll_target: Option<ScrollTarget>,
     /// Binds to `MDCTopAppBar:nav`
     ///
     /// See events docs to learn more.
-    #[prop_or_default]
-    pub onnavigationiconclick: Callback<()>,
-}
+    #[props(into)]
+    // the name cannot start with `on` or dioxus will expect an `EventHandler` which aren't static
+    // and thus cannot be used here
+    pub _onnavigation_icon_click: Option<StaticCallback<()>>,
+    pub children: Element<'a>,
 
-impl Component for MatTopAppBarFixed {
-    type Message = ();
-    type Properties = TopAppBarFixedProps;
+    #[props(into, default)]
+    pub style: String,
+    #[props(into, default)]
+    pub class: String,
+}
 
-    fn create(_: &Context<Self>) -> Self {
-        TopAppBarFixed::ensure_loaded();
-        Self {
-            node_ref: NodeRef::default(),
-            nav_listener: None,
+fn render<'a>(cx: Scope<'a, TopAppBarFixedProps<'a>>) -> Element<'a> {
+    let id = crate::use_id(cx, "top-app-bar-fixed");
+    let nav_listener = cx.use_hook(|| None);
+    let last_scroll_target = cx.use_hook(|| None);
+    if let Some(elem) = crate::get_elem_by_id(id) {
+        let target = elem.clone();
+        let top_app_bar = JsValue::from(elem).dyn_into::<TopAppBarFixed>().unwrap();
+        update_scroll_target(last_scroll_target, &cx.props.scroll_target, |value| {
+            top_app_bar.set_scroll_target(value)
+        });
+        if let Some(listener) = cx.props._onnavigation_icon_click.clone() {
+            *nav_listener = Some(EventListener::new(&target, "MDCTopAppBar:nav", move |_| {
+                listener.call(())
+            }));
         }
     }
 
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props();
-        html! {
-             <mwc-top-app-bar-fixed
-                 centerTitle={bool_to_option(props.center_title)}
-                 dense={bool_to_option(props.dense)}
-                 prominent={bool_to_option(props.prominent)}
-                 short={bool_to_option(props.short)}
-                 ref={self.node_ref.clone()}
-             >{props.children.clone()}</mwc-top-app-bar-fixed>
-        }
-    }
+    let dense = crate::utils::dense(cx, cx.props.dense);
 
-    fn changed(&mut self, _ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
-        // clear nav_listener in case a new callback was registered
-        self.nav_listener = None;
-        true
-    }
+    render! {
+        mwc-top-app-bar-fixed {
+            id: id,
+
+            centerTitle: bool_attr!(cx.props.center_title),
+            dense: bool_attr!(dense),
+            prominent: bool_attr!(cx.props.prominent),
+            short: bool_attr!(cx.props.short),
 
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        let props = ctx.props();
-        if self.nav_listener.is_none() {
-            let callback = props.onnavigationiconclick.clone();
-            let element = self.node_ref.cast::<Element>().unwrap();
+            style: string_attr!(cx.props.style),
+            class: string_attr!(cx.props.class),
 
-            self.nav_listener = Some(EventListener::new(
-                &element,
-                "MDCTopAppBar:nav",
-                move |_| {
-                    callback.emit(());
-                },
-            ));
+            &cx.props.children
         }
     }
 }
+
+component!(
+    'a,
+    MatTopAppBarFixed,
+    TopAppBarFixedProps,
+    render,
+    TopAppBarFixed,
+    "top-app-bar-fixed"
+);
@@ -0,0 +1,50 @@
+use dioxus::prelude::MountedData;
+
+/// A reference to a rendered element, e.g. the
+/// [`MenuAnchor`](crate::menu::MenuAnchor) of a menu or the
+/// [`ScrollTarget`](crate::top_app_bar::ScrollTarget) of a top app bar.
+///
+/// Strings are converted to [`ElementRef::Id`].
+#[derive(Clone, PartialEq, Debug)]
+pub enum ElementRef {
+    /// The `id` of a rendered element
+    Id(String),
+    /// An already mounted element
+    Element(web_sys::Element),
+}
+
+impl ElementRef {
+    /// Creates a reference from the data received by an `onmounted` event
+    /// handler. Returns `None` when not using the web renderer.
+    pub fn from_mounted(data: &MountedData) -> Option<Self> {
+        data.get_raw_element()
+            .ok()
+            .and_then(|elem| elem.downcast_ref::<web_sys::Element>())
+            .map(|elem| Self::Element(elem.clone()))
+    }
+
+    pub(crate) fn element(&self) -> Option<web_sys::Element> {
+        match self {
+            ElementRef::Id(id) => crate::get_elem_by_id(id),
+            ElementRef::Element(elem) => Some(elem.clone()),
+        }
+    }
+}
+
+impl From<String> for ElementRef {
+    fn from(id: String) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<&str> for ElementRef {
+    fn from(id: &str) -> Self {
+        Self::Id(id.to_owned())
+    }
+}
+
+impl From<web_sys::Element> for ElementRef {
+    fn from(elem: web_sys::Element) -> Self {
+        Self::Element(elem)
+    }
+}
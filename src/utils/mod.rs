@@ -1,17 +1,45 @@
-// mod weak_component_link;
-// pub use weak_component_link::*;
+#[allow(unused)]
+mod element_ref;
+#[allow(unused)]
+mod weak_component_link;
+#[allow(unused)]
+pub(crate) use weak_component_link::WeakComponentLink;
+
+pub use element_ref::ElementRef;
 
 use std::rc::Rc;
 
 use dioxus::prelude::*;
 
+/// The value of a `dense` prop, defaults to the shared
+/// [`Density`](crate::theming::Density). Must be called in every render, the
+/// component re-renders when the density changes.
+#[allow(unused)]
+pub(crate) fn dense(cx: &ScopeState, dense: Option<bool>) -> bool {
+    #[cfg(feature = "theming")]
+    {
+        let density = crate::theming::use_current_density(cx);
+        dense.unwrap_or(density == crate::theming::Density::Compact)
+    }
+    #[cfg(not(feature = "theming"))]
+    dense.unwrap_or_default()
+}
+
 /// See <https://github.com/DioxusLabs/dioxus/issues/1374>
-#[derive(Clone)]
 pub struct StaticCallback<T> {
     #[allow(clippy::type_complexity)]
     inner: Rc<RefCell<Box<dyn FnMut(T)>>>,
 }
 
+// not derived, as that would require `T: Clone`
+impl<T> Clone for StaticCallback<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Rc::clone(&self.inner),
+        }
+    }
+}
+
 impl<T> StaticCallback<T> {
     pub fn call(&self, arg: T) {
         (self.inner.borrow_mut())(arg)
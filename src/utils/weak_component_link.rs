@@ -1,32 +1,36 @@
 use std::cell::RefCell;
-use std::ops::Deref;
 use std::rc::Rc;
-use yew::html::Component;
-use yew::html::Scope;
 
-pub struct WeakComponentLink<COMP: Component>(Rc<RefCell<Option<Scope<COMP>>>>);
+use wasm_bindgen::JsCast;
 
-impl<COMP: Component> Clone for WeakComponentLink<COMP> {
-    fn clone(&self) -> Self {
-        Self(Rc::clone(&self.0))
-    }
-}
+/// Shared reference to the root element of a rendered component.
+///
+/// Components write the ID of their element into this on every render, which
+/// allows the public `*Link` types to call methods of the underlying web
+/// component from anywhere, e.g. event handlers.
+#[derive(Clone, Default)]
+pub(crate) struct WeakComponentLink(Rc<RefCell<Option<String>>>);
 
-impl<COMP: Component> Default for WeakComponentLink<COMP> {
-    fn default() -> Self {
-        Self(Rc::default())
+impl WeakComponentLink {
+    pub(crate) fn set_id(&self, id: &str) {
+        let mut current = self.0.borrow_mut();
+        if current.as_deref() != Some(id) {
+            *current = Some(id.to_owned());
+        }
     }
-}
-
-impl<COMP: Component> Deref for WeakComponentLink<COMP> {
-    type Target = Rc<RefCell<Option<Scope<COMP>>>>;
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    /// Returns the element this link is attached to, if it is currently
+    /// mounted.
+    pub(crate) fn cast<T: JsCast>(&self) -> Option<T> {
+        self.0
+            .borrow()
+            .as_deref()
+            .and_then(crate::get_elem_by_id)
+            .and_then(|elem| elem.dyn_into::<T>().ok())
     }
 }
 
-impl<COMP: Component> PartialEq for WeakComponentLink<COMP> {
+impl PartialEq for WeakComponentLink {
     fn eq(&self, other: &Self) -> bool {
         Rc::ptr_eq(&self.0, &other.0)
     }